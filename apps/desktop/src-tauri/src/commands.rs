@@ -1,7 +1,12 @@
+use std::sync::mpsc;
+use std::thread;
+
 use serde::Serialize;
+use tauri::ipc::Channel;
 use tauri::State;
 
-use cocommand::{CoreResponse, Workspace};
+use cocommand::builtins::clipboard::DEFAULT_POLL_INTERVAL;
+use cocommand::{CoreResponse, CoreResponseDelta, Workspace};
 
 use crate::state::AppState;
 
@@ -24,20 +29,68 @@ pub fn submit_command(text: String, state: State<'_, AppState>) -> Result<CoreRe
     core.submit_command(&text).map_err(|e| e.to_string())
 }
 
+/// Like `submit_command`, but pushes each response delta to `on_delta` as it
+/// is produced. The last delta is always `Done`.
+#[tauri::command]
+pub fn submit_command_stream(
+    text: String,
+    on_delta: Channel<CoreResponseDelta>,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let mut core = state
+        .core
+        .lock()
+        .map_err(|e| format!("lock poisoned: {e}"))?;
+    let (sender, receiver) = mpsc::channel();
+    let forwarder = thread::spawn(move || {
+        for delta in receiver {
+            if on_delta.send(delta).is_err() {
+                break;
+            }
+        }
+    });
+    let result = core.submit_command_stream(&text, sender);
+    let _ = forwarder.join();
+    result.map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub fn confirm_action(
     confirmation_id: String,
     decision: bool,
+    remember: bool,
     state: State<'_, AppState>,
 ) -> Result<CoreResponse, String> {
     let mut core = state
         .core
         .lock()
         .map_err(|e| format!("lock poisoned: {e}"))?;
-    core.confirm_action(&confirmation_id, decision)
+    core.confirm_action(&confirmation_id, decision, remember)
         .map_err(|e| e.to_string())
 }
 
+/// Turn background clipboard history recording on or off.
+#[tauri::command]
+pub fn set_clipboard_history(enabled: bool, state: State<'_, AppState>) -> Result<(), String> {
+    let mut core = state
+        .core
+        .lock()
+        .map_err(|e| format!("lock poisoned: {e}"))?;
+    if enabled {
+        core.enable_clipboard_history(DEFAULT_POLL_INTERVAL);
+    } else {
+        core.disable_clipboard_history();
+    }
+    Ok(())
+}
+
+/// Base URL of the backend server. `None` until the server has bound a port;
+/// the port may be a fallback if the default was in use.
+#[tauri::command]
+pub fn get_backend_url(state: State<'_, AppState>) -> Option<String> {
+    state.backend_url()
+}
+
 #[tauri::command]
 pub fn get_workspace_snapshot(state: State<'_, AppState>) -> Result<Workspace, String> {
     let core = state
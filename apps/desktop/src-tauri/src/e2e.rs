@@ -98,7 +98,7 @@ fn e2e_notes_delete_requires_confirmation() {
         other => panic!("expected NeedsConfirmation for notes.delete, got {other:?}"),
     };
 
-    let response = core.confirm_action(&confirmation_id, false).unwrap();
+    let response = core.confirm_action(&confirmation_id, false, false).unwrap();
     match response {
         cocommand::CoreResponse::Artifact { content, .. } => {
             assert!(content.contains("cancelled"));
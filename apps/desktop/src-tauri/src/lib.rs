@@ -2,6 +2,7 @@ use tauri::{Manager, WindowEvent};
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
 
 mod commands;
+mod platform;
 mod state;
 mod window;
 #[cfg(test)]
@@ -27,9 +28,14 @@ pub fn run() {
         .setup(|app| {
             let handle = app.handle();
             handle.global_shortcut().register("CmdOrCtrl+O")?;
+            let server_handle = handle.clone();
             tauri::async_runtime::spawn(async move {
-                if let Ok(addr) = cocommand::server::start().await {
-                    println!("Backend server listening on {}", addr);
+                match cocommand::server::start_with_retry(3, 200).await {
+                    Ok(addr) => {
+                        println!("Backend server listening on {}", addr);
+                        server_handle.state::<state::AppState>().set_backend_addr(addr);
+                    }
+                    Err(error) => eprintln!("Backend server failed to start: {}", error),
                 }
             });
             if let Some(window) = app.get_webview_window("main") {
@@ -45,9 +51,12 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             window::hide_window,
             commands::submit_command,
+            commands::submit_command_stream,
             commands::confirm_action,
+            commands::set_clipboard_history,
             commands::get_workspace_snapshot,
             commands::get_recent_actions,
+            commands::get_backend_url,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,72 @@
+use cocommand::platform::{AppAction, AppsProvider, ClipboardProvider, InstalledApp, RunningApp};
+use cocommand_platform_macos as macos;
+use serde_json::{json, Value};
+
+/// [`ClipboardProvider`] backed by the macOS general pasteboard.
+///
+/// The pasteboard only holds the current item, so history is what the
+/// core records; `get_history` returns at most that one entry.
+pub struct MacosClipboardProvider;
+
+impl ClipboardProvider for MacosClipboardProvider {
+    fn get_history(&self) -> Vec<Value> {
+        self.get_latest().into_iter().collect()
+    }
+
+    fn get_latest(&self) -> Option<Value> {
+        macos::read_clipboard_text().map(|text| json!({ "text": text }))
+    }
+
+    fn set_text(&self, text: &str) -> Result<(), String> {
+        macos::set_clipboard_text(text)
+    }
+
+    fn change_count(&self) -> Option<u64> {
+        macos::clipboard_change_count()
+    }
+}
+
+/// [`AppsProvider`] backed by `cocommand-platform-macos`.
+pub struct MacosAppsProvider;
+
+impl AppsProvider for MacosAppsProvider {
+    fn list_installed_apps(&self) -> Result<Vec<InstalledApp>, String> {
+        Ok(macos::list_installed_apps()
+            .into_iter()
+            .map(|app| InstalledApp {
+                name: app.name,
+                bundle_id: app.bundle_id,
+                path: app.path,
+            })
+            .collect())
+    }
+
+    fn list_running_apps(&self) -> Result<Vec<RunningApp>, String> {
+        Ok(macos::list_open_apps()
+            .into_iter()
+            .map(|app| RunningApp {
+                name: app.name,
+                bundle_id: app.bundle_id,
+                pid: app.pid,
+                is_active: app.is_active,
+            })
+            .collect())
+    }
+
+    fn open_app(&self, bundle_id: &str) -> Result<(), String> {
+        macos::open_app_by_bundle_id(bundle_id)
+    }
+
+    fn open_path_with(&self, path: &str, bundle_id: &str) -> Result<(), String> {
+        macos::open_path_with_bundle_id(path, bundle_id)
+    }
+
+    fn perform_app_action(&self, bundle_id: &str, action: AppAction) -> Result<(), String> {
+        let action = match action {
+            AppAction::Activate => macos::AppAction::Activate,
+            AppAction::Hide => macos::AppAction::Hide,
+            AppAction::Quit => macos::AppAction::Quit,
+        };
+        macos::perform_app_action(bundle_id, action)
+    }
+}
@@ -1,23 +1,37 @@
+use std::net::SocketAddr;
 use std::sync::{Arc, Mutex};
 
 use cocommand::Core;
 use cocommand::LlmPlanner;
+use cocommand::platform::{
+    AppsProvider, ClipboardProvider, NullAppsProvider, NullClipboardProvider,
+};
 use cocommand::storage::MemoryStorage;
 use llm_kit_openai::OpenAIClient;
 use llm_kit_openai_compatible::OpenAICompatibleClient;
 use llm_kit_provider::LanguageModel;
 
+use crate::platform::{MacosAppsProvider, MacosClipboardProvider};
+
 /// Shared application state holding the Core instance.
 /// Wrapped in Arc<Mutex<_>> because Core::submit_command requires &mut self.
 pub struct AppState {
     pub core: Arc<Mutex<Core>>,
+    /// Address the backend server bound, which may be a fallback port.
+    backend_addr: Mutex<Option<SocketAddr>>,
 }
 
 impl AppState {
     pub fn new() -> Self {
         let storage = Box::new(MemoryStorage::new());
         let mut core = Core::new(storage);
-        core.register_builtins();
+        let (clipboard_provider, apps_provider): (Arc<dyn ClipboardProvider>, Arc<dyn AppsProvider>) =
+            if cfg!(target_os = "macos") {
+                (Arc::new(MacosClipboardProvider), Arc::new(MacosAppsProvider))
+            } else {
+                (Arc::new(NullClipboardProvider), Arc::new(NullAppsProvider))
+            };
+        core.register_builtins_with(clipboard_provider, apps_provider);
         if std::env::var("COCOMMAND_LLM_API_KEY").is_ok() {
             let api_key =
                 std::env::var("COCOMMAND_LLM_API_KEY").unwrap_or_else(|_| "".to_string());
@@ -34,6 +48,22 @@ impl AppState {
         }
         Self {
             core: Arc::new(Mutex::new(core)),
+            backend_addr: Mutex::new(None),
         }
     }
+
+    pub fn set_backend_addr(&self, addr: SocketAddr) {
+        *self
+            .backend_addr
+            .lock()
+            .unwrap_or_else(|error| error.into_inner()) = Some(addr);
+    }
+
+    /// Base URL of the backend server, or `None` until it has started.
+    pub fn backend_url(&self) -> Option<String> {
+        self.backend_addr
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .map(|addr| format!("http://{addr}"))
+    }
 }
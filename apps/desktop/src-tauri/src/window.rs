@@ -15,8 +15,26 @@ pub fn toggle_main_window(app: &tauri::AppHandle) {
         if is_visible {
             let _ = window.hide();
         } else {
+            position_on_cursor_display(&window);
             let _ = window.show();
             let _ = window.set_focus();
         }
     }
 }
+
+/// Center the window horizontally on the display under the mouse cursor,
+/// a third of the way down. Leaves the window where it is if no display is
+/// found.
+fn position_on_cursor_display(window: &tauri::WebviewWindow) {
+    let Some(display) = cocommand_platform_macos::display_under_cursor() else {
+        return;
+    };
+    let Ok(size) = window.outer_size() else {
+        return;
+    };
+    let scale = window.scale_factor().unwrap_or(display.scale_factor);
+    let size = size.to_logical::<f64>(scale);
+    let x = display.x + (display.width - size.width) / 2.0;
+    let y = display.y + (display.height - size.height) / 3.0;
+    let _ = window.set_position(tauri::LogicalPosition::new(x, y));
+}
@@ -1,3 +1,12 @@
-const BASE_URL = "http://127.0.0.1:4840";
+import { invoke } from "@tauri-apps/api/core";
 
-export { BASE_URL };
+const DEFAULT_BASE_URL = "http://127.0.0.1:4840";
+
+// The server falls back to another port when 4840 stays in use, so ask the
+// app which address it actually bound.
+async function getBaseUrl(): Promise<string> {
+  const url = await invoke<string | null>("get_backend_url");
+  return url ?? DEFAULT_BASE_URL;
+}
+
+export { DEFAULT_BASE_URL, getBaseUrl };
@@ -3,6 +3,7 @@
 export {
   submitCommand,
   confirmAction,
+  setClipboardHistory,
   getRecentActions,
   getWorkspaceSnapshot,
   hideWindow,
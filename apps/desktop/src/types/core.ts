@@ -1,4 +1,4 @@
-import { invoke } from "@tauri-apps/api/core";
+import { Channel, invoke } from "@tauri-apps/api/core";
 
 // --- Bridge types (exact match of Rust serde output) ---
 
@@ -13,6 +13,10 @@ export type CoreResponse =
   | { type: "Confirmation"; confirmation_id: string; prompt: string; description: string }
   | { type: "Error"; message: string };
 
+export type CoreResponseDelta =
+  | { type: "Text"; chunk: string }
+  | { type: "Done"; response: CoreResponse };
+
 export interface ActionSummary {
   id: string;
   description: string;
@@ -125,16 +129,31 @@ export async function submitCommand(text: string): Promise<CoreResponse> {
   return invoke("submit_command", { text });
 }
 
+export async function submitCommandStream(
+  text: string,
+  onDelta: (delta: CoreResponseDelta) => void
+): Promise<void> {
+  const channel = new Channel<CoreResponseDelta>();
+  channel.onmessage = onDelta;
+  return invoke("submit_command_stream", { text, onDelta: channel });
+}
+
 export async function confirmAction(
   confirmationId: string,
-  decision: boolean
+  decision: boolean,
+  remember = false
 ): Promise<CoreResponse> {
   return invoke("confirm_action", {
     confirmation_id: confirmationId,
     decision,
+    remember,
   });
 }
 
+export async function setClipboardHistory(enabled: boolean): Promise<void> {
+  return invoke("set_clipboard_history", { enabled });
+}
+
 export async function getRecentActions(limit: number): Promise<ActionSummary[]> {
   return invoke("get_recent_actions", { limit });
 }
@@ -1,36 +1,45 @@
 //! Built-in app implementations (Core-8).
 //!
-//! Provides Clipboard, Notes, and Calculator as always-available built-in applications.
+//! Provides Clipboard, Notes, Calculator, and Apps as always-available built-in applications.
 //! Each registers its tools and routing metadata at startup via [`register_builtins`].
 
+pub mod apps;
 pub mod calculator;
 pub mod clipboard;
 pub mod notes;
 
 use std::sync::Arc;
 
-use crate::platform::{ClipboardProvider, NullClipboardProvider};
+use crate::platform::{AppsProvider, ClipboardProvider, NullAppsProvider, NullClipboardProvider};
 use crate::routing::Router;
 use crate::tools::registry::ToolRegistry;
 
 /// Register all built-in app tools and routing metadata.
 ///
 /// Call this during core startup to make built-in apps available for
-/// routing and execution. Uses [`NullClipboardProvider`] by default;
-/// call [`register_builtins_with`] to supply a real provider.
+/// routing and execution. Uses [`NullClipboardProvider`] and
+/// [`NullAppsProvider`] by default; call [`register_builtins_with`] to supply
+/// real providers.
 pub fn register_builtins(registry: &mut ToolRegistry, router: &mut Router) {
-    register_builtins_with(registry, router, Arc::new(NullClipboardProvider));
+    register_builtins_with(
+        registry,
+        router,
+        Arc::new(NullClipboardProvider),
+        Arc::new(NullAppsProvider),
+    );
 }
 
-/// Register all built-in apps with an explicit clipboard provider.
+/// Register all built-in apps with explicit platform providers.
 pub fn register_builtins_with(
     registry: &mut ToolRegistry,
     router: &mut Router,
     clipboard_provider: Arc<dyn ClipboardProvider>,
+    apps_provider: Arc<dyn AppsProvider>,
 ) {
     clipboard::register(registry, router, clipboard_provider);
     notes::register(registry, router);
     calculator::register(registry, router);
+    apps::register(registry, router, apps_provider);
 }
 
 #[cfg(test)]
@@ -38,7 +47,7 @@ mod tests {
     use super::*;
     use crate::command::ParsedCommand;
     use crate::permissions::PermissionStore;
-    use crate::platform::MockClipboardProvider;
+    use crate::platform::{MockAppsProvider, MockClipboardProvider};
     use crate::storage::{MemoryStorage, Storage};
     use crate::tools::executor::{execute_tool, ToolExecutionOutcome};
     use crate::workspace::Workspace;
@@ -54,7 +63,12 @@ mod tests {
     ) -> (ToolRegistry, Router, Workspace, Box<dyn Storage>, PermissionStore) {
         let mut registry = ToolRegistry::new();
         let mut router = Router::new();
-        register_builtins_with(&mut registry, &mut router, provider);
+        register_builtins_with(
+            &mut registry,
+            &mut router,
+            provider,
+            Arc::new(MockAppsProvider::new(vec![], vec![])),
+        );
         let workspace = Workspace::new("test-session".to_string());
         let storage: Box<dyn Storage> = Box::new(MemoryStorage::new());
         let permission_store = PermissionStore::new();
@@ -90,6 +104,8 @@ mod tests {
             raw_text: "show last note".to_string(),
             normalized_text: "show last note".to_string(),
             tags: vec![],
+            tokens: vec![],
+            flags: Default::default(),
         };
         let routing_result = router.route(&cmd);
         assert!(!routing_result.candidates.is_empty());
@@ -146,6 +162,8 @@ mod tests {
             raw_text: "delete last note".to_string(),
             normalized_text: "delete last note".to_string(),
             tags: vec![],
+            tokens: vec![],
+            flags: Default::default(),
         };
         let routing_result = router.route(&cmd);
         assert!(!routing_result.candidates.is_empty());
@@ -179,6 +197,8 @@ mod tests {
             raw_text: "calculate 2+2".to_string(),
             normalized_text: "calculate 2+2".to_string(),
             tags: vec![],
+            tokens: vec![],
+            flags: Default::default(),
         };
         let result = router.route(&cmd);
         assert!(result.candidates.iter().any(|c| c.app_id == "calculator"));
@@ -187,6 +207,8 @@ mod tests {
             raw_text: "show clipboard".to_string(),
             normalized_text: "show clipboard".to_string(),
             tags: vec![],
+            tokens: vec![],
+            flags: Default::default(),
         };
         let result = router.route(&cmd);
         assert!(result.candidates.iter().any(|c| c.app_id == "clipboard"));
@@ -195,9 +217,21 @@ mod tests {
             raw_text: "create a note".to_string(),
             normalized_text: "create a note".to_string(),
             tags: vec![],
+            tokens: vec![],
+            flags: Default::default(),
         };
         let result = router.route(&cmd);
         assert!(result.candidates.iter().any(|c| c.app_id == "notes"));
+
+        let cmd = ParsedCommand {
+            raw_text: "quit slack app".to_string(),
+            normalized_text: "quit slack app".to_string(),
+            tags: vec![],
+            tokens: vec![],
+            flags: Default::default(),
+        };
+        let result = router.route(&cmd);
+        assert!(result.candidates.iter().any(|c| c.app_id == "apps"));
     }
 
     #[test]
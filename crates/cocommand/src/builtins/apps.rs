@@ -0,0 +1,462 @@
+//! Apps built-in app: list installed/running apps, open apps, and app actions.
+
+use std::sync::Arc;
+
+use serde_json::json;
+
+use crate::error::{CoreError, CoreResult};
+use crate::platform::{AppAction, AppsProvider};
+use crate::routing::RoutingMetadata;
+use crate::tools::schema::{RiskLevel, ToolDefinition};
+use crate::tools::registry::ToolRegistry;
+use crate::routing::Router;
+
+/// App identifier.
+pub const APP_ID: &str = "apps";
+
+/// Register apps tools and routing metadata.
+pub fn register(registry: &mut ToolRegistry, router: &mut Router, provider: Arc<dyn AppsProvider>) {
+    registry.register_kernel_tool(list_installed_tool(Arc::clone(&provider)));
+    registry.register_kernel_tool(list_running_tool(Arc::clone(&provider)));
+    registry.register_kernel_tool(open_tool(Arc::clone(&provider)));
+    registry.register_kernel_tool(open_with_tool(Arc::clone(&provider)));
+    registry.register_kernel_tool(action_tool(provider));
+    router.register(routing_metadata());
+}
+
+/// Routing metadata for the apps app.
+fn routing_metadata() -> RoutingMetadata {
+    RoutingMetadata {
+        app_id: APP_ID.to_string(),
+        keywords: vec![
+            "app".into(),
+            "apps".into(),
+            "application".into(),
+            "launch".into(),
+            "quit".into(),
+        ],
+        examples: vec![
+            "open spotify".into(),
+            "quit slack".into(),
+            "list running apps".into(),
+            "open report.pdf in preview".into(),
+            "hide this app".into(),
+        ],
+        verbs: vec![
+            "open".into(),
+            "launch".into(),
+            "quit".into(),
+            "hide".into(),
+            "activate".into(),
+            "switch".into(),
+        ],
+        objects: vec![
+            "app".into(),
+            "apps".into(),
+            "application".into(),
+            "applications".into(),
+        ],
+        weights: Default::default(),
+    }
+}
+
+/// Tool definition for `apps.list_installed`.
+fn list_installed_tool(provider: Arc<dyn AppsProvider>) -> ToolDefinition {
+    ToolDefinition {
+        tool_id: "apps.list_installed".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "query": {"type": "string"},
+                "limit": {"type": "integer"}
+            }
+        }),
+        output_schema: json!({
+            "type": "object",
+            "properties": {
+                "apps": {"type": "array"},
+                "count": {"type": "integer"}
+            }
+        }),
+        risk_level: RiskLevel::Safe,
+        is_kernel: false,
+        handler: Box::new(move |args, _ctx| {
+            let query = args
+                .get("query")
+                .and_then(|v| v.as_str())
+                .map(|q| q.to_lowercase());
+            let limit = args
+                .get("limit")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(100) as usize;
+
+            let apps: Vec<_> = provider
+                .list_installed_apps()
+                .map_err(CoreError::Internal)?
+                .into_iter()
+                .filter(|app| match &query {
+                    Some(q) => app.name.to_lowercase().contains(q.as_str()),
+                    None => true,
+                })
+                .take(limit)
+                .collect();
+            let count = apps.len();
+
+            Ok(json!({
+                "apps": apps,
+                "count": count
+            }))
+        }),
+    }
+}
+
+/// Tool definition for `apps.list_running`.
+fn list_running_tool(provider: Arc<dyn AppsProvider>) -> ToolDefinition {
+    ToolDefinition {
+        tool_id: "apps.list_running".to_string(),
+        input_schema: json!({
+            "type": "object"
+        }),
+        output_schema: json!({
+            "type": "object",
+            "properties": {
+                "apps": {"type": "array"},
+                "count": {"type": "integer"}
+            }
+        }),
+        risk_level: RiskLevel::Safe,
+        is_kernel: false,
+        handler: Box::new(move |_args, _ctx| {
+            let apps = provider.list_running_apps().map_err(CoreError::Internal)?;
+            let count = apps.len();
+
+            Ok(json!({
+                "apps": apps,
+                "count": count
+            }))
+        }),
+    }
+}
+
+/// Tool definition for `apps.open`.
+///
+/// Accepts either a `bundle_id` or an app `name`, which is resolved against
+/// the installed apps (case-insensitive exact match).
+fn open_tool(provider: Arc<dyn AppsProvider>) -> ToolDefinition {
+    ToolDefinition {
+        tool_id: "apps.open".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "bundle_id": {"type": "string"},
+                "name": {"type": "string"}
+            }
+        }),
+        output_schema: json!({
+            "type": "object",
+            "properties": {
+                "bundle_id": {"type": "string"},
+                "opened": {"type": "boolean"}
+            }
+        }),
+        risk_level: RiskLevel::Safe,
+        is_kernel: false,
+        handler: Box::new(move |args, _ctx| {
+            let bundle_id = resolve_bundle_id(args, provider.as_ref())?;
+            provider.open_app(&bundle_id).map_err(CoreError::Internal)?;
+
+            Ok(json!({
+                "bundle_id": bundle_id,
+                "opened": true
+            }))
+        }),
+    }
+}
+
+/// Tool definition for `apps.open_with`.
+///
+/// Opens an existing `path` in the installed app named by `app`, which may be
+/// an app name (case-insensitive) or a bundle ID.
+fn open_with_tool(provider: Arc<dyn AppsProvider>) -> ToolDefinition {
+    ToolDefinition {
+        tool_id: "apps.open_with".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "required": ["path", "app"],
+            "properties": {
+                "path": {"type": "string"},
+                "app": {"type": "string"}
+            }
+        }),
+        output_schema: json!({
+            "type": "object",
+            "properties": {
+                "path": {"type": "string"},
+                "bundle_id": {"type": "string"},
+                "opened": {"type": "boolean"}
+            }
+        }),
+        risk_level: RiskLevel::Safe,
+        is_kernel: false,
+        handler: Box::new(move |args, _ctx| {
+            let path = args
+                .get("path")
+                .and_then(|v| v.as_str())
+                .filter(|p| !p.trim().is_empty())
+                .ok_or_else(|| CoreError::InvalidInput("missing required field: path".to_string()))?;
+            let app = args
+                .get("app")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| CoreError::InvalidInput("missing required field: app".to_string()))?;
+            if !std::path::Path::new(path).exists() {
+                return Err(CoreError::InvalidInput(format!("path does not exist: {path}")));
+            }
+
+            let bundle_id = provider
+                .list_installed_apps()
+                .map_err(CoreError::Internal)?
+                .into_iter()
+                .filter_map(|installed| {
+                    let matches = installed.name.eq_ignore_ascii_case(app)
+                        || installed.bundle_id.as_deref() == Some(app);
+                    if matches { installed.bundle_id } else { None }
+                })
+                .next()
+                .ok_or_else(|| CoreError::InvalidInput(format!("no installed app found named '{app}'")))?;
+            provider
+                .open_path_with(path, &bundle_id)
+                .map_err(CoreError::Internal)?;
+
+            Ok(json!({
+                "path": path,
+                "bundle_id": bundle_id,
+                "opened": true
+            }))
+        }),
+    }
+}
+
+/// Tool definition for `apps.action`.
+///
+/// Requires confirmation because `quit` can discard unsaved work.
+fn action_tool(provider: Arc<dyn AppsProvider>) -> ToolDefinition {
+    ToolDefinition {
+        tool_id: "apps.action".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "required": ["action"],
+            "properties": {
+                "action": {"type": "string"},
+                "bundle_id": {"type": "string"},
+                "name": {"type": "string"}
+            }
+        }),
+        output_schema: json!({
+            "type": "object",
+            "properties": {
+                "bundle_id": {"type": "string"},
+                "action": {"type": "string"}
+            }
+        }),
+        risk_level: RiskLevel::Confirm,
+        is_kernel: false,
+        handler: Box::new(move |args, _ctx| {
+            let action: AppAction = args
+                .get("action")
+                .cloned()
+                .and_then(|v| serde_json::from_value(v).ok())
+                .ok_or_else(|| {
+                    CoreError::InvalidInput(
+                        "action must be one of: activate, hide, quit".to_string(),
+                    )
+                })?;
+            let bundle_id = resolve_bundle_id(args, provider.as_ref())?;
+            provider
+                .perform_app_action(&bundle_id, action)
+                .map_err(CoreError::Internal)?;
+
+            Ok(json!({
+                "bundle_id": bundle_id,
+                "action": action
+            }))
+        }),
+    }
+}
+
+/// Resolve the target bundle ID from `bundle_id`, falling back to a `name` lookup.
+fn resolve_bundle_id(args: &serde_json::Value, provider: &dyn AppsProvider) -> CoreResult<String> {
+    if let Some(bundle_id) = args.get("bundle_id").and_then(|v| v.as_str()) {
+        return Ok(bundle_id.to_string());
+    }
+
+    let name = args
+        .get("name")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| CoreError::InvalidInput("either 'bundle_id' or 'name' is required".to_string()))?;
+
+    let running = provider.list_running_apps().unwrap_or_default();
+    let installed = provider.list_installed_apps().map_err(CoreError::Internal)?;
+    // Skip entries without a bundle id so a running helper process cannot
+    // hide an installed app of the same name.
+    running
+        .into_iter()
+        .map(|app| (app.name, app.bundle_id))
+        .chain(installed.into_iter().map(|app| (app.name, app.bundle_id)))
+        .find_map(|(app_name, bundle_id)| {
+            bundle_id.filter(|_| app_name.eq_ignore_ascii_case(name))
+        })
+        .ok_or_else(|| CoreError::InvalidInput(format!("no app found named '{name}'")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::{InstalledApp, MockAppsProvider, NullAppsProvider, RunningApp};
+    use crate::storage::{MemoryStorage, Storage};
+    use crate::tools::schema::ExecutionContext;
+    use crate::workspace::Workspace;
+
+    fn mock_provider() -> Arc<MockAppsProvider> {
+        Arc::new(MockAppsProvider::new(
+            vec![
+                InstalledApp {
+                    name: "Spotify".to_string(),
+                    bundle_id: Some("com.spotify.client".to_string()),
+                    path: "/Applications/Spotify.app".to_string(),
+                },
+                InstalledApp {
+                    name: "Slack".to_string(),
+                    bundle_id: Some("com.tinyspeck.slackmacgap".to_string()),
+                    path: "/Applications/Slack.app".to_string(),
+                },
+            ],
+            vec![RunningApp {
+                name: "Slack".to_string(),
+                bundle_id: Some("com.tinyspeck.slackmacgap".to_string()),
+                pid: 42,
+                is_active: true,
+            }],
+        ))
+    }
+
+    fn run(tool: &ToolDefinition, args: serde_json::Value) -> CoreResult<serde_json::Value> {
+        let mut ws = Workspace::new("test".to_string());
+        let mut storage: Box<dyn Storage> = Box::new(MemoryStorage::new());
+        let (event_log, clipboard_store) = storage.split_event_clipboard_mut();
+        let mut ctx = ExecutionContext {
+            workspace: &mut ws,
+            event_log,
+            clipboard_store,
+        };
+        (tool.handler)(&args, &mut ctx)
+    }
+
+    #[test]
+    fn list_installed_filters_by_query() {
+        let tool = list_installed_tool(mock_provider());
+        let result = run(&tool, json!({"query": "spot"})).unwrap();
+        assert_eq!(result["count"], 1);
+        assert_eq!(result["apps"][0]["bundle_id"], "com.spotify.client");
+    }
+
+    #[test]
+    fn list_running_returns_apps() {
+        let tool = list_running_tool(mock_provider());
+        let result = run(&tool, json!({})).unwrap();
+        assert_eq!(result["count"], 1);
+        assert_eq!(result["apps"][0]["pid"], 42);
+    }
+
+    #[test]
+    fn open_resolves_name_to_bundle_id() {
+        let provider = mock_provider();
+        let tool = open_tool(Arc::clone(&provider) as Arc<dyn AppsProvider>);
+        let result = run(&tool, json!({"name": "spotify"})).unwrap();
+        assert_eq!(result["bundle_id"], "com.spotify.client");
+        assert_eq!(provider.calls(), vec!["open:com.spotify.client"]);
+    }
+
+    #[test]
+    fn open_skips_name_match_without_bundle_id() {
+        let provider = Arc::new(MockAppsProvider::new(
+            vec![InstalledApp {
+                name: "Spotify".to_string(),
+                bundle_id: Some("com.spotify.client".to_string()),
+                path: "/Applications/Spotify.app".to_string(),
+            }],
+            vec![RunningApp {
+                name: "Spotify".to_string(),
+                bundle_id: None,
+                pid: 7,
+                is_active: false,
+            }],
+        ));
+        let tool = open_tool(Arc::clone(&provider) as Arc<dyn AppsProvider>);
+        let result = run(&tool, json!({"name": "Spotify"})).unwrap();
+        assert_eq!(result["bundle_id"], "com.spotify.client");
+        assert_eq!(provider.calls(), vec!["open:com.spotify.client"]);
+    }
+
+    #[test]
+    fn open_unknown_name_fails() {
+        let tool = open_tool(mock_provider());
+        assert!(run(&tool, json!({"name": "Nonexistent"})).is_err());
+        assert!(run(&tool, json!({})).is_err());
+    }
+
+    #[test]
+    fn open_with_resolves_app_name_or_bundle_id() {
+        let provider = mock_provider();
+        let tool = open_with_tool(Arc::clone(&provider) as Arc<dyn AppsProvider>);
+        let path = env!("CARGO_MANIFEST_DIR");
+
+        let result = run(&tool, json!({"path": path, "app": "spotify"})).unwrap();
+        assert_eq!(result["bundle_id"], "com.spotify.client");
+        run(&tool, json!({"path": path, "app": "com.tinyspeck.slackmacgap"})).unwrap();
+
+        assert_eq!(
+            provider.calls(),
+            vec![
+                format!("open_with:com.spotify.client:{path}"),
+                format!("open_with:com.tinyspeck.slackmacgap:{path}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn open_with_rejects_unknown_app_or_missing_path() {
+        let provider = mock_provider();
+        let tool = open_with_tool(Arc::clone(&provider) as Arc<dyn AppsProvider>);
+        let path = env!("CARGO_MANIFEST_DIR");
+
+        let err = run(&tool, json!({"path": path, "app": "Preview"})).unwrap_err();
+        assert!(err.to_string().contains("no installed app found"));
+        let err = run(&tool, json!({"path": "/no/such/file.pdf", "app": "Spotify"})).unwrap_err();
+        assert!(err.to_string().contains("path does not exist"));
+        assert!(provider.calls().is_empty());
+    }
+
+    #[test]
+    fn action_quits_by_name() {
+        let provider = mock_provider();
+        let tool = action_tool(Arc::clone(&provider) as Arc<dyn AppsProvider>);
+        assert_eq!(tool.risk_level, RiskLevel::Confirm);
+        let result = run(&tool, json!({"action": "quit", "name": "Slack"})).unwrap();
+        assert_eq!(result["action"], "quit");
+        assert_eq!(provider.calls(), vec!["quit:com.tinyspeck.slackmacgap"]);
+    }
+
+    #[test]
+    fn action_rejects_unknown_action() {
+        let tool = action_tool(mock_provider());
+        let result = run(&tool, json!({"action": "explode", "bundle_id": "com.example"}));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn unsupported_platform_surfaces_error() {
+        let tool = list_installed_tool(Arc::new(NullAppsProvider));
+        let err = run(&tool, json!({})).unwrap_err();
+        assert!(err.to_string().contains("not supported on this platform"));
+    }
+}
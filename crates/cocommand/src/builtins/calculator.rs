@@ -43,6 +43,7 @@ fn routing_metadata() -> RoutingMetadata {
             "math".into(),
             "calculation".into(),
         ],
+        weights: Default::default(),
     }
 }
 
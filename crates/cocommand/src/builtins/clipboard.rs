@@ -1,25 +1,41 @@
-//! Clipboard built-in app: list and latest tools.
-
-use std::sync::Arc;
-use std::time::SystemTime;
+//! Clipboard built-in app: list, latest, get, restore, and clear tools.
+//!
+//! History is recorded when the tools read the clipboard and, once enabled
+//! through [`Core::enable_clipboard_history`](crate::Core::enable_clipboard_history),
+//! by a background [`ClipboardRecorder`]. Only text entries are recorded;
+//! images and other non-text contents are skipped.
+
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
 
 use serde_json::json;
 use uuid::Uuid;
 
+use crate::error::{CoreError, CoreResult};
 use crate::platform::ClipboardProvider;
 use crate::routing::RoutingMetadata;
-use crate::storage::ClipboardEntry;
+use crate::storage::{ClipboardEntry, ClipboardStore, Storage};
 use crate::tools::schema::{RiskLevel, ToolDefinition};
 use crate::tools::registry::ToolRegistry;
 use crate::routing::Router;
+use crate::utils::sync::{lock_recovering, PoisonedLocks};
+use crate::utils::time;
 
 /// App identifier.
 pub const APP_ID: &str = "clipboard";
 
+/// Default delay between clipboard polls of a [`ClipboardRecorder`].
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 /// Register clipboard tools and routing metadata.
 pub fn register(registry: &mut ToolRegistry, router: &mut Router, provider: Arc<dyn ClipboardProvider>) {
     registry.register_kernel_tool(list_tool(Arc::clone(&provider)));
-    registry.register_kernel_tool(latest_tool(provider));
+    registry.register_kernel_tool(latest_tool(Arc::clone(&provider)));
+    registry.register_kernel_tool(get_tool());
+    registry.register_kernel_tool(restore_tool(provider));
+    registry.register_kernel_tool(clear_tool());
     router.register(routing_metadata());
 }
 
@@ -44,12 +60,15 @@ fn routing_metadata() -> RoutingMetadata {
             "list".into(),
             "get".into(),
             "paste".into(),
+            "restore".into(),
+            "clear".into(),
         ],
         objects: vec![
             "clipboard".into(),
             "history".into(),
             "copied".into(),
         ],
+        weights: Default::default(),
     }
 }
 
@@ -84,22 +103,15 @@ fn list_tool(provider: Arc<dyn ClipboardProvider>) -> ToolDefinition {
                     ctx.clipboard_store.push(ClipboardEntry {
                         id: Uuid::new_v4(),
                         content: text,
-                        copied_at: SystemTime::now(),
+                        copied_at: time::now(),
                     });
                 }
             }
 
             // Read history from the store (most-recent-first).
             let entries: Vec<_> = ctx.clipboard_store.list(limit)
-                .into_iter()
-                .map(|e| json!({
-                    "id": e.id.to_string(),
-                    "content": e.content,
-                    "copied_at": e.copied_at
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_secs(),
-                }))
+                .iter()
+                .map(entry_to_json)
                 .collect();
             let count = entries.len();
 
@@ -135,7 +147,7 @@ fn latest_tool(provider: Arc<dyn ClipboardProvider>) -> ToolDefinition {
                         ctx.clipboard_store.push(ClipboardEntry {
                             id: Uuid::new_v4(),
                             content: text,
-                            copied_at: SystemTime::now(),
+                            copied_at: time::now(),
                         });
                     }
                     Ok(json!({
@@ -152,6 +164,216 @@ fn latest_tool(provider: Arc<dyn ClipboardProvider>) -> ToolDefinition {
     }
 }
 
+/// Tool definition for `clipboard.get`.
+fn get_tool() -> ToolDefinition {
+    ToolDefinition {
+        tool_id: "clipboard.get".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "required": ["id"],
+            "properties": {
+                "id": {"type": "string"}
+            }
+        }),
+        output_schema: json!({
+            "type": "object",
+            "properties": {
+                "entry": {},
+                "found": {"type": "boolean"}
+            }
+        }),
+        risk_level: RiskLevel::Safe,
+        is_kernel: false,
+        handler: Box::new(|args, ctx| {
+            let id = parse_entry_id(args)?;
+            match ctx.clipboard_store.get(id) {
+                Some(entry) => Ok(json!({
+                    "entry": entry_to_json(&entry),
+                    "found": true
+                })),
+                None => Ok(json!({
+                    "entry": null,
+                    "found": false
+                })),
+            }
+        }),
+    }
+}
+
+/// Tool definition for `clipboard.restore`.
+///
+/// Copies a stored history entry back onto the system clipboard. Needs
+/// confirmation because it overwrites whatever the user has copied.
+fn restore_tool(provider: Arc<dyn ClipboardProvider>) -> ToolDefinition {
+    ToolDefinition {
+        tool_id: "clipboard.restore".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "required": ["id"],
+            "properties": {
+                "id": {"type": "string"}
+            }
+        }),
+        output_schema: json!({
+            "type": "object",
+            "properties": {
+                "restored": {"type": "boolean"}
+            }
+        }),
+        risk_level: RiskLevel::Confirm,
+        is_kernel: false,
+        handler: Box::new(move |args, ctx| {
+            let id = parse_entry_id(args)?;
+            let entry = ctx.clipboard_store.get(id).ok_or_else(|| {
+                CoreError::InvalidInput(format!("clipboard entry not found: {id}"))
+            })?;
+            provider
+                .set_text(&entry.content)
+                .map_err(CoreError::Internal)?;
+            Ok(json!({ "restored": true }))
+        }),
+    }
+}
+
+/// Tool definition for `clipboard.clear`.
+fn clear_tool() -> ToolDefinition {
+    ToolDefinition {
+        tool_id: "clipboard.clear".to_string(),
+        input_schema: json!({
+            "type": "object"
+        }),
+        output_schema: json!({
+            "type": "object",
+            "properties": {
+                "cleared": {"type": "integer"}
+            }
+        }),
+        risk_level: RiskLevel::Destructive,
+        is_kernel: false,
+        handler: Box::new(|_args, ctx| {
+            let cleared = ctx.clipboard_store.len();
+            ctx.clipboard_store.clear();
+            Ok(json!({ "cleared": cleared }))
+        }),
+    }
+}
+
+/// Background thread recording clipboard changes into the clipboard store.
+///
+/// macOS offers no pasteboard change notification, so changes are observed
+/// by polling the provider's [`change_count`](ClipboardProvider::change_count)
+/// and reading the contents only when it moves. Providers without a counter
+/// (other platforms) fall back to reading and comparing the contents on every
+/// poll. Recording stops when the recorder is dropped.
+pub struct ClipboardRecorder {
+    stop: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ClipboardRecorder {
+    /// Start polling `provider` every `interval`, pushing new text entries
+    /// into the clipboard store of `storage`.
+    pub fn spawn(
+        provider: Arc<dyn ClipboardProvider>,
+        storage: Arc<Mutex<Box<dyn Storage>>>,
+        poisoned_locks: Arc<PoisonedLocks>,
+        interval: Duration,
+    ) -> Self {
+        let (stop, stopped) = mpsc::channel::<()>();
+        let thread = std::thread::spawn(move || {
+            let mut poller = ClipboardPoller::new(provider);
+            loop {
+                poller.poll(&storage, &poisoned_locks);
+                match stopped.recv_timeout(interval) {
+                    Err(RecvTimeoutError::Timeout) => continue,
+                    _ => break,
+                }
+            }
+        });
+        Self {
+            stop: Some(stop),
+            thread: Some(thread),
+        }
+    }
+}
+
+impl Drop for ClipboardRecorder {
+    fn drop(&mut self) {
+        // Dropping the sender wakes the thread and ends its loop.
+        self.stop.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// One step of clipboard recording, separate from the thread for testing.
+struct ClipboardPoller {
+    provider: Arc<dyn ClipboardProvider>,
+    last_change: Option<u64>,
+}
+
+impl ClipboardPoller {
+    fn new(provider: Arc<dyn ClipboardProvider>) -> Self {
+        Self {
+            provider,
+            last_change: None,
+        }
+    }
+
+    /// Record the current clipboard text if it changed. `storage` is only
+    /// locked when there is something to compare or record. Returns whether
+    /// an entry was pushed.
+    fn poll(&mut self, storage: &Mutex<Box<dyn Storage>>, poisoned_locks: &PoisonedLocks) -> bool {
+        if let Some(count) = self.provider.change_count() {
+            if self.last_change == Some(count) {
+                return false;
+            }
+            self.last_change = Some(count);
+        }
+        let Some(text) = self.provider.get_latest().as_ref().and_then(extract_text) else {
+            return false;
+        };
+        let mut storage = lock_recovering(storage, "storage", poisoned_locks);
+        record_text(storage.clipboard_mut(), text)
+    }
+}
+
+/// Push `text` unless it is already the latest entry.
+fn record_text(store: &mut dyn ClipboardStore, text: String) -> bool {
+    if store.latest().is_some_and(|latest| latest.content == text) {
+        return false;
+    }
+    store.push(ClipboardEntry {
+        id: Uuid::new_v4(),
+        content: text,
+        copied_at: time::now(),
+    });
+    true
+}
+
+/// Parse the required `id` argument as a clipboard entry UUID.
+fn parse_entry_id(args: &serde_json::Value) -> CoreResult<Uuid> {
+    let raw = args
+        .get("id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| CoreError::InvalidInput("missing 'id'".to_string()))?;
+    Uuid::parse_str(raw)
+        .map_err(|_| CoreError::InvalidInput(format!("invalid clipboard entry id: {raw}")))
+}
+
+/// Serialize a stored entry in the same shape `clipboard.list` returns.
+fn entry_to_json(entry: &ClipboardEntry) -> serde_json::Value {
+    json!({
+        "id": entry.id.to_string(),
+        "content": entry.content,
+        "copied_at": entry.copied_at
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+    })
+}
+
 /// Extract text content from a clipboard provider entry.
 /// Handles both plain string values and objects with a "text" field.
 fn extract_text(value: &serde_json::Value) -> Option<String> {
@@ -170,10 +392,12 @@ fn extract_text(value: &serde_json::Value) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::SystemTime;
     use crate::storage::{MemoryStorage, Storage};
     use crate::platform::MockClipboardProvider;
     use crate::tools::schema::ExecutionContext;
     use crate::workspace::Workspace;
+    use std::sync::atomic::{AtomicU64, Ordering};
 
     #[test]
     fn list_returns_empty_when_no_history() {
@@ -362,6 +586,205 @@ mod tests {
         assert!(result["entry"].is_null());
     }
 
+    fn seeded_storage() -> (Box<dyn Storage>, Uuid) {
+        let mut storage: Box<dyn Storage> = Box::new(MemoryStorage::new());
+        let id = Uuid::new_v4();
+        storage.clipboard_mut().push(ClipboardEntry {
+            id,
+            content: "stored".to_string(),
+            copied_at: SystemTime::now(),
+        });
+        storage.clipboard_mut().push(ClipboardEntry {
+            id: Uuid::new_v4(),
+            content: "newer".to_string(),
+            copied_at: SystemTime::now(),
+        });
+        (storage, id)
+    }
+
+    #[test]
+    fn get_returns_entry_by_id() {
+        let tool = get_tool();
+        let mut ws = Workspace::new("test".to_string());
+        let (mut storage, id) = seeded_storage();
+        let (event_log, clipboard_store) = storage.split_event_clipboard_mut();
+        let mut ctx = ExecutionContext {
+            workspace: &mut ws,
+            event_log,
+            clipboard_store,
+        };
+        let result = (tool.handler)(&json!({"id": id.to_string()}), &mut ctx).unwrap();
+        assert_eq!(result["found"], true);
+        assert_eq!(result["entry"]["content"], "stored");
+
+        let missing = (tool.handler)(&json!({"id": Uuid::new_v4().to_string()}), &mut ctx).unwrap();
+        assert_eq!(missing["found"], false);
+    }
+
+    #[test]
+    fn get_rejects_malformed_id() {
+        let tool = get_tool();
+        let mut ws = Workspace::new("test".to_string());
+        let mut storage: Box<dyn Storage> = Box::new(MemoryStorage::new());
+        let (event_log, clipboard_store) = storage.split_event_clipboard_mut();
+        let mut ctx = ExecutionContext {
+            workspace: &mut ws,
+            event_log,
+            clipboard_store,
+        };
+        assert!((tool.handler)(&json!({"id": "not-a-uuid"}), &mut ctx).is_err());
+    }
+
+    #[test]
+    fn restore_sets_system_clipboard() {
+        let provider = Arc::new(MockClipboardProvider::new(vec![]));
+        let tool = restore_tool(Arc::clone(&provider) as Arc<dyn ClipboardProvider>);
+        assert_eq!(tool.risk_level, RiskLevel::Confirm);
+        let mut ws = Workspace::new("test".to_string());
+        let (mut storage, id) = seeded_storage();
+        let (event_log, clipboard_store) = storage.split_event_clipboard_mut();
+        let mut ctx = ExecutionContext {
+            workspace: &mut ws,
+            event_log,
+            clipboard_store,
+        };
+        let result = (tool.handler)(&json!({"id": id.to_string()}), &mut ctx).unwrap();
+        assert_eq!(result["restored"], true);
+        assert_eq!(provider.get_latest().unwrap()["text"], "stored");
+    }
+
+    #[test]
+    fn restore_unknown_entry_fails() {
+        let provider = Arc::new(MockClipboardProvider::new(vec![]));
+        let tool = restore_tool(provider);
+        let mut ws = Workspace::new("test".to_string());
+        let mut storage: Box<dyn Storage> = Box::new(MemoryStorage::new());
+        let (event_log, clipboard_store) = storage.split_event_clipboard_mut();
+        let mut ctx = ExecutionContext {
+            workspace: &mut ws,
+            event_log,
+            clipboard_store,
+        };
+        let result = (tool.handler)(&json!({"id": Uuid::new_v4().to_string()}), &mut ctx);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn clear_empties_history() {
+        let tool = clear_tool();
+        assert_eq!(tool.risk_level, RiskLevel::Destructive);
+        let mut ws = Workspace::new("test".to_string());
+        let (mut storage, _id) = seeded_storage();
+        let (event_log, clipboard_store) = storage.split_event_clipboard_mut();
+        let mut ctx = ExecutionContext {
+            workspace: &mut ws,
+            event_log,
+            clipboard_store,
+        };
+        let result = (tool.handler)(&json!({}), &mut ctx).unwrap();
+        assert_eq!(result["cleared"], 2);
+        assert!(ctx.clipboard_store.is_empty());
+    }
+
+    /// Provider with an explicit change counter, like the macOS pasteboard.
+    /// Counts content reads so tests can check they are skipped.
+    struct CountingProvider {
+        inner: MockClipboardProvider,
+        count: AtomicU64,
+        reads: AtomicU64,
+    }
+
+    impl ClipboardProvider for CountingProvider {
+        fn get_history(&self) -> Vec<serde_json::Value> {
+            self.inner.get_history()
+        }
+
+        fn get_latest(&self) -> Option<serde_json::Value> {
+            self.reads.fetch_add(1, Ordering::SeqCst);
+            self.inner.get_latest()
+        }
+
+        fn set_text(&self, text: &str) -> Result<(), String> {
+            self.count.fetch_add(1, Ordering::SeqCst);
+            self.inner.set_text(text)
+        }
+
+        fn change_count(&self) -> Option<u64> {
+            Some(self.count.load(Ordering::SeqCst))
+        }
+    }
+
+    fn shared_storage() -> Mutex<Box<dyn Storage>> {
+        Mutex::new(Box::new(MemoryStorage::new()))
+    }
+
+    #[test]
+    fn poller_records_changes_by_comparing_contents() {
+        let provider = Arc::new(MockClipboardProvider::new(vec![json!({"text": "first"})]));
+        let mut poller = ClipboardPoller::new(Arc::clone(&provider) as Arc<dyn ClipboardProvider>);
+        let storage = shared_storage();
+        let poisoned = Mutex::new(Vec::new());
+
+        assert!(poller.poll(&storage, &poisoned));
+        assert!(!poller.poll(&storage, &poisoned));
+        provider.set_text("second").unwrap();
+        assert!(poller.poll(&storage, &poisoned));
+
+        let contents: Vec<_> = storage
+            .lock()
+            .unwrap()
+            .clipboard()
+            .list(10)
+            .into_iter()
+            .map(|e| e.content)
+            .collect();
+        assert_eq!(contents, vec!["second", "first"]);
+    }
+
+    #[test]
+    fn poller_reads_contents_only_when_change_count_moves() {
+        let provider = Arc::new(CountingProvider {
+            inner: MockClipboardProvider::new(vec![]),
+            count: AtomicU64::new(0),
+            reads: AtomicU64::new(0),
+        });
+        let mut poller = ClipboardPoller::new(Arc::clone(&provider) as Arc<dyn ClipboardProvider>);
+        let storage = shared_storage();
+        let poisoned = Mutex::new(Vec::new());
+
+        provider.set_text("copied").unwrap();
+        assert!(poller.poll(&storage, &poisoned));
+        assert!(!poller.poll(&storage, &poisoned));
+        assert_eq!(provider.reads.load(Ordering::SeqCst), 1);
+        assert_eq!(storage.lock().unwrap().clipboard().len(), 1);
+    }
+
+    #[test]
+    fn poller_skips_non_text_entries() {
+        let provider = Arc::new(MockClipboardProvider::new(vec![json!({"image": "png"})]));
+        let mut poller = ClipboardPoller::new(provider);
+        let storage = shared_storage();
+
+        assert!(!poller.poll(&storage, &Mutex::new(Vec::new())));
+        assert!(storage.lock().unwrap().clipboard().is_empty());
+    }
+
+    #[test]
+    fn recorder_stops_when_dropped() {
+        let provider = Arc::new(MockClipboardProvider::new(vec![json!({"text": "copied"})]));
+        let storage = Arc::new(shared_storage());
+        let recorder = ClipboardRecorder::spawn(
+            provider,
+            Arc::clone(&storage),
+            Arc::new(Mutex::new(Vec::new())),
+            Duration::from_millis(1),
+        );
+        drop(recorder);
+
+        // The first poll runs before the thread checks for a stop.
+        assert_eq!(storage.lock().unwrap().clipboard().len(), 1);
+    }
+
     #[test]
     fn extract_text_from_string_value() {
         let val = json!("hello");
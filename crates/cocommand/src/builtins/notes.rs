@@ -7,6 +7,7 @@ use crate::routing::RoutingMetadata;
 use crate::tools::schema::{RiskLevel, ToolDefinition};
 use crate::tools::registry::ToolRegistry;
 use crate::routing::Router;
+use crate::utils::time;
 
 /// App identifier.
 pub const APP_ID: &str = "notes";
@@ -53,6 +54,7 @@ fn routing_metadata() -> RoutingMetadata {
             "notes".into(),
             "memo".into(),
         ],
+        weights: Default::default(),
     }
 }
 
@@ -159,10 +161,7 @@ fn create_tool() -> ToolDefinition {
                 .to_string();
 
             let id = Uuid::new_v4().to_string();
-            let now = std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_secs();
+            let now = time::now_secs();
 
             let note = json!({
                 "id": id,
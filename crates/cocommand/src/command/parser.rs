@@ -1,26 +1,106 @@
+use std::collections::BTreeMap;
+
 use super::types::ParsedCommand;
 
-/// Parse a raw command string, extracting `@app` tags and normalizing the remaining text.
+/// A lexed word and whether it came from a quoted phrase.
+struct Word {
+    text: String,
+    quoted: bool,
+}
+
+/// Parse a raw command string, extracting `@app` tags and `--flags` and
+/// normalizing the remaining text.
+///
+/// Double-quoted phrases form a single token (`\"` escapes a quote inside
+/// one) and are never treated as tags or flags. A flag consumes the following
+/// word as its value unless that word is itself a tag or flag.
 pub fn parse(input: &str) -> ParsedCommand {
     let raw_text = input.to_string();
     let mut tags = Vec::new();
-    let mut remaining = Vec::new();
-
-    for token in input.split_whitespace() {
-        if is_tag(token) {
-            tags.push(token[1..].to_lowercase());
+    let mut tokens = Vec::new();
+    let mut flags = BTreeMap::new();
+
+    let mut words = lex(input).into_iter().peekable();
+    while let Some(word) = words.next() {
+        if word.quoted {
+            tokens.push(word.text);
+        } else if is_tag(&word.text) {
+            tags.push(word.text[1..].to_lowercase());
+        } else if let Some((name, inline_value)) = parse_flag(&word.text) {
+            let value = match inline_value {
+                Some(value) => value,
+                None => match words.peek() {
+                    Some(next) if next.quoted || !(is_tag(&next.text) || parse_flag(&next.text).is_some()) => {
+                        words.next().map(|w| w.text).unwrap_or_default()
+                    }
+                    _ => "true".to_string(),
+                },
+            };
+            flags.insert(name, value);
         } else {
-            remaining.push(token);
+            tokens.push(word.text);
         }
     }
 
-    let normalized_text = remaining.join(" ");
+    let normalized_text = tokens.join(" ");
 
     ParsedCommand {
         raw_text,
         normalized_text,
         tags,
+        tokens,
+        flags,
+    }
+}
+
+/// Split input on whitespace, keeping double-quoted phrases together.
+/// An unterminated quote runs to the end of the input.
+fn lex(input: &str) -> Vec<Word> {
+    let mut words = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '"' {
+            chars.next();
+            let mut text = String::new();
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' if chars.peek() == Some(&'"') => text.push(chars.next().unwrap_or('"')),
+                    '"' => break,
+                    _ => text.push(c),
+                }
+            }
+            let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            if !text.is_empty() {
+                words.push(Word { text, quoted: true });
+            }
+        } else {
+            let mut text = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                text.push(c);
+                chars.next();
+            }
+            words.push(Word { text, quoted: false });
+        }
     }
+
+    words
+}
+
+/// Parse `--name` or `--name=value`, returning the lowercased name and any inline value.
+fn parse_flag(token: &str) -> Option<(String, Option<String>)> {
+    let rest = token.strip_prefix("--")?;
+    let (name, value) = match rest.split_once('=') {
+        Some((name, value)) => (name, Some(value.to_string())),
+        None => (rest, None),
+    };
+    let valid = !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    valid.then(|| (name.to_lowercase(), value))
 }
 
 /// A valid tag starts with `@` followed by one or more `[a-zA-Z0-9_-]` characters,
@@ -100,6 +180,66 @@ mod tests {
         assert_eq!(result.normalized_text, "@ hello");
     }
 
+    #[test]
+    fn quoted_phrase_is_one_token() {
+        let result = parse(r#"create note "Shopping  List" today"#);
+        assert_eq!(result.tokens, vec!["create", "note", "Shopping List", "today"]);
+        assert_eq!(result.normalized_text, "create note Shopping List today");
+    }
+
+    #[test]
+    fn escaped_quotes_inside_phrase() {
+        let result = parse(r#"rename "say \"hi\" now""#);
+        assert_eq!(result.tokens, vec!["rename", r#"say "hi" now"#]);
+    }
+
+    #[test]
+    fn unterminated_quote_runs_to_end() {
+        let result = parse(r#"find "open ended"#);
+        assert_eq!(result.tokens, vec!["find", "open ended"]);
+    }
+
+    #[test]
+    fn quoted_tags_and_flags_stay_text() {
+        let result = parse(r#"write "@notes --pin""#);
+        assert!(result.tags.is_empty());
+        assert!(result.flags.is_empty());
+        assert_eq!(result.tokens, vec!["write", "@notes --pin"]);
+    }
+
+    #[test]
+    fn extracts_flags() {
+        let result = parse(r#"create note "shopping list" --pin --Due tomorrow --color=Red"#);
+        assert_eq!(result.tokens, vec!["create", "note", "shopping list"]);
+        assert_eq!(result.flags.get("pin").map(String::as_str), Some("true"));
+        assert_eq!(result.flags.get("due").map(String::as_str), Some("tomorrow"));
+        assert_eq!(result.flags.get("color").map(String::as_str), Some("Red"));
+        assert_eq!(result.normalized_text, "create note shopping list");
+    }
+
+    #[test]
+    fn flag_followed_by_flag_or_tag_is_boolean() {
+        let result = parse("--pin --archive @notes done");
+        assert_eq!(result.flags.get("pin").map(String::as_str), Some("true"));
+        assert_eq!(result.flags.get("archive").map(String::as_str), Some("true"));
+        assert_eq!(result.tags, vec!["notes"]);
+        assert_eq!(result.tokens, vec!["done"]);
+    }
+
+    #[test]
+    fn flag_value_can_be_quoted() {
+        let result = parse(r#"remind --at "5 pm" me"#);
+        assert_eq!(result.flags.get("at").map(String::as_str), Some("5 pm"));
+        assert_eq!(result.tokens, vec!["remind", "me"]);
+    }
+
+    #[test]
+    fn bare_dashes_are_text() {
+        let result = parse("a -- b");
+        assert!(result.flags.is_empty());
+        assert_eq!(result.normalized_text, "a -- b");
+    }
+
     #[test]
     fn tag_with_hyphen_and_underscore() {
         let result = parse("@my-app @another_app test");
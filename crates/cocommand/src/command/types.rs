@@ -1,10 +1,19 @@
+use std::collections::BTreeMap;
+
 /// Result of parsing a raw command string.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ParsedCommand {
     /// The original unmodified input.
     pub raw_text: String,
-    /// Input after trimming, collapsing whitespace, and removing tags.
+    /// Input after trimming, collapsing whitespace, and removing tags and flags.
+    /// Quoted phrases appear without their quotes.
     pub normalized_text: String,
     /// Extracted `@app` tags in order of appearance, lowercased.
     pub tags: Vec<String>,
+    /// Words of `normalized_text`, with each quoted phrase kept as one token.
+    /// Case is preserved.
+    pub tokens: Vec<String>,
+    /// `--flag` and `--flag value` / `--flag=value` arguments, keyed by
+    /// lowercased flag name. Flags without a value map to `"true"`.
+    pub flags: BTreeMap<String, String>,
 }
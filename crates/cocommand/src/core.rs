@@ -1,21 +1,37 @@
-use std::time::SystemTime;
+use std::collections::HashSet;
+use std::time::Duration;
 use uuid::Uuid;
 
 use crate::command;
-use crate::error::CoreResult;
+use crate::error::{CoreError, CoreResult};
 use crate::events::Event;
-use crate::permissions::PermissionStore;
-use crate::routing::Router;
+use crate::permissions::risk::risk_for_tool;
+use crate::permissions::{PermissionDecision, PermissionGrant, PermissionScope, PermissionStore};
+use crate::platform::{AppsProvider, ClipboardProvider, NullClipboardProvider};
+use crate::routing::{RouteCandidate, Router};
 use crate::storage::Storage;
-use crate::types::{ActionSummary, ArtifactAction, CoreResponse, RoutedCandidate};
-use crate::workspace::state::{Timestamp, WorkspaceMode};
+use crate::utils::sync::{lock_recovering, report_recovered_locks, PoisonedLocks};
+use crate::utils::time;
+use crate::types::{ActionSubscription, ActionSummary, ArtifactAction, CoreResponse, CoreResponseDelta, RoutedCandidate};
+use crate::workspace::state::{ApplicationStatus, InstanceId, Timestamp, WorkspaceMode};
 use crate::workspace::Workspace;
 use crate::builtins;
-use crate::planner::{Planner, PlannerError, PlannerInput, PlannerOutput, ToolSpec, StubPlanner};
-use crate::tools::registry::ToolRegistry;
-use crate::llm::{build_toolset, ToolRuntime};
+use crate::builtins::clipboard::ClipboardRecorder;
+use crate::planner::{Planner, PlannerError, PlannerInput, PlannerOutput, ToolSpec, StubPlanner, TextSink};
+use crate::tools::breaker::{BreakerConfig, CircuitBreaker};
+use crate::tools::executor::{APPROVAL_REQUIRED, INVALID_TOOL_CALL, TOOL_DENIED, TOOL_UNAVAILABLE};
+use crate::tools::registry::{ToolInfo, ToolRegistry};
+use crate::llm::{build_toolset, build_toolset_with_instances, ToolRuntime};
 use llm_kit_core::tool::ToolSet;
-use std::sync::{Arc, Mutex};
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Storage kv namespace and key holding remembered permission grants.
+const PERMISSIONS_NAMESPACE: &str = "permissions";
+const REMEMBERED_GRANTS_KEY: &str = "remembered_grants";
+
+/// Number of top routed candidates whose app tools are offered to the planner.
+const DEFAULT_CANDIDATE_TOOL_SCOPE: usize = 3;
 
 /// Primary facade for the cocommand engine.
 ///
@@ -28,8 +44,16 @@ pub struct Core {
     storage: Arc<Mutex<Box<dyn Storage>>>,
     registry: Arc<Mutex<ToolRegistry>>,
     permission_store: Arc<Mutex<PermissionStore>>,
+    breaker: Arc<Mutex<CircuitBreaker>>,
     planner: Arc<dyn Planner>,
     planner_label: String,
+    candidate_tool_scope: usize,
+    /// Provider the clipboard built-ins were registered with.
+    clipboard_provider: Arc<dyn ClipboardProvider>,
+    /// Background clipboard history recorder, present only while enabled.
+    clipboard_recorder: Option<ClipboardRecorder>,
+    /// Locks recovered from poisoning, shared with tool runtimes.
+    poisoned_locks: Arc<PoisonedLocks>,
 }
 
 impl Core {
@@ -45,21 +69,7 @@ impl Core {
         let workspace = match snapshot {
             Some(snap) => match serde_json::from_value::<Workspace>(snap.data) {
                 Ok(mut ws) => {
-                    // Sanitize ephemeral state that must not survive a restart.
-                    ws.confirmation_pending = None;
-                    if ws.mode == WorkspaceMode::AwaitingConfirmation {
-                        ws.mode = WorkspaceMode::Idle;
-                    }
-
-                    // Expire follow-up if TTL has passed or turns exhausted.
-                    let now = Self::now();
-                    if ws.follow_up.is_some() && !ws.is_follow_up_valid(now) {
-                        ws.follow_up = None;
-                        if ws.mode == WorkspaceMode::FollowUpActive {
-                            ws.mode = WorkspaceMode::Idle;
-                        }
-                    }
-
+                    Self::sanitize_restored(&mut ws);
                     ws
                 }
                 Err(_) => {
@@ -70,7 +80,7 @@ impl Core {
                         .event_log_mut()
                         .append(Event::ErrorRaised {
                         id: Uuid::new_v4(),
-                        timestamp: SystemTime::now(),
+                        timestamp: time::now(),
                         code: "snapshot_corrupt".to_string(),
                         message: "Failed to deserialize workspace snapshot; starting fresh."
                             .to_string(),
@@ -81,27 +91,40 @@ impl Core {
             None => Workspace::new(Uuid::new_v4().to_string()),
         };
 
+        let permission_store = Self::load_permission_store(&**storage.lock().expect("storage lock"));
+
         Core {
             workspace: Arc::new(Mutex::new(workspace)),
             router: Router::new(),
             storage,
             registry: Arc::new(Mutex::new(ToolRegistry::new())),
-            permission_store: Arc::new(Mutex::new(PermissionStore::new())),
+            permission_store: Arc::new(Mutex::new(permission_store)),
             planner: Arc::new(StubPlanner),
             planner_label: "stub".to_string(),
+            candidate_tool_scope: DEFAULT_CANDIDATE_TOOL_SCOPE,
+            breaker: Arc::new(Mutex::new(CircuitBreaker::default())),
+            clipboard_provider: Arc::new(NullClipboardProvider),
+            clipboard_recorder: None,
+            poisoned_locks: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
     /// Create a `Core` with an existing workspace, router, and storage (for testing).
     pub fn with_state(workspace: Workspace, router: Router, storage: Box<dyn Storage>) -> Self {
+        let permission_store = Self::load_permission_store(&*storage);
         Core {
             workspace: Arc::new(Mutex::new(workspace)),
             router,
             storage: Arc::new(Mutex::new(storage)),
             registry: Arc::new(Mutex::new(ToolRegistry::new())),
-            permission_store: Arc::new(Mutex::new(PermissionStore::new())),
+            permission_store: Arc::new(Mutex::new(permission_store)),
             planner: Arc::new(StubPlanner),
             planner_label: "stub".to_string(),
+            candidate_tool_scope: DEFAULT_CANDIDATE_TOOL_SCOPE,
+            breaker: Arc::new(Mutex::new(CircuitBreaker::default())),
+            clipboard_provider: Arc::new(NullClipboardProvider),
+            clipboard_recorder: None,
+            poisoned_locks: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
@@ -113,11 +136,48 @@ impl Core {
     const PREVIEW_VERBS: &'static [&'static str] = &["show", "view", "get", "display", "preview", "read"];
 
     pub fn submit_command(&mut self, text: &str) -> CoreResult<CoreResponse> {
+        self.submit_command_inner(text, None)
+    }
+
+    /// Submit a command, streaming planner text to `sender` as it is produced.
+    ///
+    /// Sends zero or more [`CoreResponseDelta::Text`] chunks followed by exactly
+    /// one [`CoreResponseDelta::Done`], then returns. If the command fails, the
+    /// terminal delta carries a [`CoreResponse::Error`] and the error is also
+    /// returned. Send failures (receiver dropped) are ignored.
+    pub fn submit_command_stream(
+        &mut self,
+        text: &str,
+        sender: Sender<CoreResponseDelta>,
+    ) -> CoreResult<()> {
+        let text_sender = sender.clone();
+        let on_text: TextSink = Arc::new(move |chunk| {
+            let _ = text_sender.send(CoreResponseDelta::Text {
+                chunk: chunk.to_string(),
+            });
+        });
+
+        let result = self.submit_command_inner(text, Some(on_text));
+        let response = match &result {
+            Ok(response) => response.clone(),
+            Err(error) => CoreResponse::Error {
+                message: error.to_string(),
+            },
+        };
+        let _ = sender.send(CoreResponseDelta::Done { response });
+        result.map(|_| ())
+    }
+
+    fn submit_command_inner(
+        &mut self,
+        text: &str,
+        on_text: Option<TextSink>,
+    ) -> CoreResult<CoreResponse> {
         {
-            let mut storage = self.storage.lock().expect("storage lock");
+            let mut storage = self.lock_storage();
             storage.event_log_mut().append(Event::UserMessage {
             id: Uuid::new_v4(),
-            timestamp: SystemTime::now(),
+            timestamp: time::now(),
             text: text.to_string(),
             });
         }
@@ -127,7 +187,7 @@ impl Core {
 
         // Check follow-up validity and route accordingly.
         let (follow_up_ctx, workspace_snapshot) = {
-            let mut workspace = self.workspace.lock().expect("workspace lock");
+            let mut workspace = self.lock_workspace();
 
             // If a confirmation is pending, the user must resolve it first.
             if let Some(cp) = &workspace.confirmation_pending {
@@ -136,7 +196,7 @@ impl Core {
                     prompt: format!("Pending action: {}", cp.tool_id),
                     description: "Please confirm or deny before submitting new commands.".to_string(),
                 };
-                let mut storage = self.storage.lock().expect("storage lock");
+                let mut storage = self.lock_storage();
                 self.save_snapshot_locked(&mut workspace, &mut *storage);
                 return Ok(response);
             }
@@ -146,7 +206,7 @@ impl Core {
             } else if workspace.follow_up.is_some() {
                 // TTL expired or turns exhausted — expire and return error.
                 workspace.expire_follow_up();
-                let mut storage = self.storage.lock().expect("storage lock");
+                let mut storage = self.lock_storage();
                 self.save_snapshot_locked(&mut workspace, &mut *storage);
                 return Ok(CoreResponse::Error {
                     message: "Follow-up expired. Please provide the full command.".to_string(),
@@ -189,20 +249,27 @@ impl Core {
                 "[planner] using={} instance_id={} command={}",
                 self.planner_label, instance_id, parsed.raw_text
             );
-            let output = self.run_planner(PlannerInput {
-                command: parsed.clone(),
-                candidates: candidates.clone(),
-                workspace: workspace_snapshot,
-                tools: self.collect_tool_specs(),
-                toolset: Some(self.build_llm_toolset(instance_id)),
-            })
+            let scoped_instances = self.scoped_instance_ids(&candidates);
+            let output = self.run_planner(
+                PlannerInput {
+                    command: parsed.clone(),
+                    candidates: candidates.clone(),
+                    workspace: workspace_snapshot,
+                    tools: self.collect_tool_specs(&scoped_instances),
+                    toolset: Some(build_toolset_with_instances(
+                        self.tool_runtime(instance_id),
+                        &scoped_instances,
+                    )),
+                },
+                on_text,
+            )
             .map(Some)
             .unwrap_or_else(|err| {
                 println!("[planner] error={err:?}");
-                let mut storage = self.storage.lock().expect("storage lock");
+                let mut storage = self.lock_storage();
                 storage.event_log_mut().append(Event::ErrorRaised {
                     id: Uuid::new_v4(),
-                    timestamp: SystemTime::now(),
+                    timestamp: time::now(),
                     code: "planner_error".to_string(),
                     message: format!("{err:?}"),
                 });
@@ -227,12 +294,12 @@ impl Core {
                 output.metadata.planner_id,
                 output.plan.steps.len()
             );
-            let mut storage = self.storage.lock().expect("storage lock");
+            let mut storage = self.lock_storage();
             let event_log = storage.event_log_mut();
             for step in &output.plan.steps {
                 event_log.append(Event::ToolCallProposed {
                     id: Uuid::new_v4(),
-                    timestamp: SystemTime::now(),
+                    timestamp: time::now(),
                     tool_id: step.tool_id.clone(),
                     args: step.args.clone(),
                 });
@@ -240,14 +307,14 @@ impl Core {
         }
 
         {
-            let mut workspace = self.workspace.lock().expect("workspace lock");
+            let mut workspace = self.lock_workspace();
             if let Some(cp) = &workspace.confirmation_pending {
                 let response = CoreResponse::Confirmation {
                     confirmation_id: cp.confirmation_id.clone(),
                     prompt: format!("Pending action: {}", cp.tool_id),
                     description: "Please confirm or deny before submitting new commands.".to_string(),
                 };
-                let mut storage = self.storage.lock().expect("storage lock");
+                let mut storage = self.lock_storage();
                 self.save_snapshot_locked(&mut workspace, &mut *storage);
                 return Ok(response);
             }
@@ -286,7 +353,7 @@ impl Core {
                     .map(|t| t.trim())
                     .filter(|t| !t.is_empty())
                 {
-                    let workspace = self.workspace.lock().expect("workspace lock");
+                    let workspace = self.lock_workspace();
                     let actions = self.build_artifact_actions(&workspace);
                     CoreResponse::Artifact {
                         content: text.to_string(),
@@ -298,7 +365,7 @@ impl Core {
                         "Routed to {} (score: {:.1}): {}",
                         top.app_id, top.score, top.explanation
                     );
-                    let workspace = self.workspace.lock().expect("workspace lock");
+                    let workspace = self.lock_workspace();
                     let actions = self.build_artifact_actions(&workspace);
                     CoreResponse::Artifact { content, actions }
                 }
@@ -308,15 +375,15 @@ impl Core {
                     "Routed to {} (score: {:.1}): {}",
                     top.app_id, top.score, top.explanation
                 );
-                let workspace = self.workspace.lock().expect("workspace lock");
+                let workspace = self.lock_workspace();
                 let actions = self.build_artifact_actions(&workspace);
                 CoreResponse::Artifact { content, actions }
             }
         };
 
         {
-            let mut workspace = self.workspace.lock().expect("workspace lock");
-            let mut storage = self.storage.lock().expect("storage lock");
+            let mut workspace = self.lock_workspace();
+            let mut storage = self.lock_storage();
             self.save_snapshot_locked(&mut workspace, &mut *storage);
         }
         Ok(response)
@@ -329,7 +396,7 @@ impl Core {
         entity_ids: Vec<String>,
         app_id: String,
     ) {
-        let mut workspace = self.workspace.lock().expect("workspace lock");
+        let mut workspace = self.lock_workspace();
         workspace.enter_follow_up(command, entity_ids, app_id);
     }
 
@@ -337,50 +404,73 @@ impl Core {
     ///
     /// Looks up the pending confirmation in the workspace by ID.
     /// Returns an `Artifact` on success or an `Error` if no matching
-    /// confirmation is found.
+    /// confirmation is found. Approving with `remember` also grants the
+    /// tool permanently at its scope, so it runs without asking next time.
     pub fn confirm_action(
         &mut self,
         confirmation_id: &str,
         decision: bool,
+        remember: bool,
     ) -> CoreResult<CoreResponse> {
-        let mut workspace = self.workspace.lock().expect("workspace lock");
-        let mut storage = self.storage.lock().expect("storage lock");
-        let pending = workspace.confirmation_pending.as_ref();
-
-        match pending {
-            Some(cp) if cp.confirmation_id == confirmation_id => {
-                let tool_id = cp.tool_id.clone();
-                workspace.clear_confirmation();
+        let (response, approved_tool) = {
+            let mut workspace = self.lock_workspace();
+            let mut storage = self.lock_storage();
+            let pending = workspace.confirmation_pending.as_ref();
+
+            match pending {
+                Some(cp) if cp.confirmation_id == confirmation_id => {
+                    let tool_id = cp.tool_id.clone();
+                    workspace.clear_confirmation();
+
+                    let response = if decision {
+                        CoreResponse::Artifact {
+                            content: format!("Action confirmed: {tool_id}"),
+                            actions: vec![],
+                        }
+                    } else {
+                        CoreResponse::Artifact {
+                            content: format!("Action cancelled: {tool_id}"),
+                            actions: vec![],
+                        }
+                    };
 
-                let response = if decision {
-                    CoreResponse::Artifact {
-                        content: format!("Action confirmed: {tool_id}"),
-                        actions: vec![],
-                    }
-                } else {
-                    CoreResponse::Artifact {
-                        content: format!("Action cancelled: {tool_id}"),
-                        actions: vec![],
-                    }
-                };
+                    self.save_snapshot_locked(&mut workspace, &mut *storage);
+                    (response, decision.then_some(tool_id))
+                }
+                Some(_) => (
+                    CoreResponse::Error {
+                        message: format!(
+                            "No pending confirmation with id '{confirmation_id}'."
+                        ),
+                    },
+                    None,
+                ),
+                None => (
+                    CoreResponse::Error {
+                        message: "No action pending confirmation.".to_string(),
+                    },
+                    None,
+                ),
+            }
+        };
 
-                self.save_snapshot_locked(&mut workspace, &mut *storage);
-                Ok(response)
+        if let Some(tool_id) = approved_tool.filter(|_| remember) {
+            let scope = self
+                .lock_registry()
+                .catalog(None)
+                .into_iter()
+                .find(|tool| tool.tool_id == tool_id)
+                .map(|tool| risk_for_tool(&tool.risk_level));
+            if let Some(scope) = scope {
+                self.grant_permission(&tool_id, scope, true);
             }
-            Some(_) => Ok(CoreResponse::Error {
-                message: format!(
-                    "No pending confirmation with id '{confirmation_id}'."
-                ),
-            }),
-            None => Ok(CoreResponse::Error {
-                message: "No action pending confirmation.".to_string(),
-            }),
         }
+        Ok(response)
     }
 
     /// Retrieve a snapshot of the current workspace state.
     pub fn get_workspace_snapshot(&self) -> CoreResult<Workspace> {
-        Ok(self.workspace.lock().expect("workspace lock").clone())
+        Ok(self.lock_workspace().clone())
     }
 
     /// Retrieve recent actions up to `limit`.
@@ -389,26 +479,33 @@ impl Core {
     /// derived from structural metadata at write time and never contain
     /// raw user text or sensitive content.
     pub fn get_recent_actions(&self, limit: usize) -> CoreResult<Vec<ActionSummary>> {
-        let storage = self.storage.lock().expect("storage lock");
+        let storage = self.lock_storage();
         let records = storage.event_log().tail(limit);
-        let summaries = records
-            .into_iter()
-            .map(|record| ActionSummary {
-                id: record.event.id().to_string(),
-                description: record.summary,
-            })
-            .collect();
-        Ok(summaries)
+        Ok(records.into_iter().map(ActionSummary::from).collect())
+    }
+
+    /// Subscribe to actions: yields the last `limit` summaries, then one for
+    /// every event logged afterwards.
+    ///
+    /// Summaries are the same redaction-safe form as
+    /// [`get_recent_actions`](Self::get_recent_actions).
+    pub fn subscribe_actions(&self, limit: usize) -> ActionSubscription {
+        // Subscribe under the same lock as the tail so no event is missed or
+        // delivered twice.
+        let mut storage = self.lock_storage();
+        let records = storage.event_log_mut().subscribe();
+        let recent = storage.event_log().tail(limit);
+        ActionSubscription::new(recent.into_iter().map(ActionSummary::from).collect(), records)
     }
 
     /// Get a reference to the storage backend.
-    pub fn storage(&self) -> std::sync::MutexGuard<'_, Box<dyn Storage>> {
-        self.storage.lock().expect("storage lock")
+    pub fn storage(&self) -> MutexGuard<'_, Box<dyn Storage>> {
+        self.lock_storage()
     }
 
     /// Get a mutable reference to the storage backend.
-    pub fn storage_mut(&mut self) -> std::sync::MutexGuard<'_, Box<dyn Storage>> {
-        self.storage.lock().expect("storage lock")
+    pub fn storage_mut(&mut self) -> MutexGuard<'_, Box<dyn Storage>> {
+        self.lock_storage()
     }
 
     /// Get a mutable reference to the router for registration.
@@ -418,13 +515,13 @@ impl Core {
 
     /// Get a reference to the workspace.
     pub fn workspace(&self) -> Workspace {
-        self.workspace.lock().expect("workspace lock").clone()
+        self.lock_workspace().clone()
     }
 
     /// Get a mutable reference to the workspace (for testing).
     #[cfg(test)]
-    pub fn workspace_mut(&mut self) -> std::sync::MutexGuard<'_, Workspace> {
-        self.workspace.lock().expect("workspace lock")
+    pub fn workspace_mut(&mut self) -> MutexGuard<'_, Workspace> {
+        self.lock_workspace()
     }
 
     /// Build a tool runtime for llm-kit execution.
@@ -434,6 +531,8 @@ impl Core {
             workspace: Arc::clone(&self.workspace),
             storage: Arc::clone(&self.storage),
             permission_store: Arc::clone(&self.permission_store),
+            breaker: Arc::clone(&self.breaker),
+            poisoned_locks: Arc::clone(&self.poisoned_locks),
             instance_id: instance_id.into(),
         })
     }
@@ -444,20 +543,155 @@ impl Core {
     }
 
     /// Access the tool registry (for registration).
-    pub fn registry_mut(&mut self) -> std::sync::MutexGuard<'_, ToolRegistry> {
-        self.registry.lock().expect("registry lock")
+    pub fn registry_mut(&mut self) -> MutexGuard<'_, ToolRegistry> {
+        self.lock_registry()
+    }
+
+    /// List every registered tool with its schemas, independent of any instance.
+    pub fn tool_catalog(&self) -> Vec<ToolInfo> {
+        self.lock_registry().catalog(None)
+    }
+
+    /// Like [`tool_catalog`](Self::tool_catalog), but instance tools are only
+    /// listed for instances that are active in the workspace.
+    pub fn active_tool_catalog(&self) -> Vec<ToolInfo> {
+        let active: HashSet<InstanceId> = self
+            .lock_workspace()
+            .instances
+            .values()
+            .filter(|instance| instance.status == ApplicationStatus::Active)
+            .map(|instance| instance.instance_id.clone())
+            .collect();
+        self.lock_registry().catalog(Some(&active))
     }
 
     /// Register built-in tools and router metadata.
     pub fn register_builtins(&mut self) {
-        let mut registry = self.registry.lock().expect("registry lock");
+        let mut registry = lock_recovering(&self.registry, "registry", &self.poisoned_locks);
         let router = &mut self.router;
         builtins::register_builtins(&mut *registry, router);
     }
 
-    /// Access the permission store (for updates).
-    pub fn permission_store_mut(&mut self) -> std::sync::MutexGuard<'_, PermissionStore> {
-        self.permission_store.lock().expect("permission store lock")
+    /// Register built-in tools backed by explicit platform providers.
+    pub fn register_builtins_with(
+        &mut self,
+        clipboard_provider: Arc<dyn ClipboardProvider>,
+        apps_provider: Arc<dyn AppsProvider>,
+    ) {
+        self.clipboard_provider = Arc::clone(&clipboard_provider);
+        let mut registry = lock_recovering(&self.registry, "registry", &self.poisoned_locks);
+        let router = &mut self.router;
+        builtins::register_builtins_with(&mut *registry, router, clipboard_provider, apps_provider);
+    }
+
+    /// Start recording clipboard changes into history, checking every
+    /// `interval`.
+    ///
+    /// Off by default for privacy: until this is called, history only grows
+    /// when a clipboard tool reads the clipboard. Restarts the recorder if it
+    /// is already running.
+    pub fn enable_clipboard_history(&mut self, interval: Duration) {
+        self.clipboard_recorder = None;
+        self.clipboard_recorder = Some(ClipboardRecorder::spawn(
+            Arc::clone(&self.clipboard_provider),
+            Arc::clone(&self.storage),
+            Arc::clone(&self.poisoned_locks),
+            interval,
+        ));
+    }
+
+    /// Stop the background clipboard recorder. Recorded history is kept.
+    pub fn disable_clipboard_history(&mut self) {
+        self.clipboard_recorder = None;
+    }
+
+    /// Whether the background clipboard recorder is running.
+    pub fn clipboard_history_enabled(&self) -> bool {
+        self.clipboard_recorder.is_some()
+    }
+
+    /// Get a mutable reference to the permission store (for testing).
+    ///
+    /// Changes made through it are not persisted; use
+    /// [`Core::set_permission_decision`] or [`Core::grant_permission`].
+    #[cfg(test)]
+    pub fn permission_store_mut(&mut self) -> MutexGuard<'_, PermissionStore> {
+        self.lock_permission_store()
+    }
+
+    /// Store a session-only decision for a tool at the given scope.
+    ///
+    /// Replaces any remembered grant for the pair, which is dropped from
+    /// storage too.
+    pub fn set_permission_decision(
+        &mut self,
+        tool_id: &str,
+        scope: PermissionScope,
+        decision: PermissionDecision,
+    ) {
+        let grants = {
+            let mut store = self.lock_permission_store();
+            store.set_decision(tool_id.to_string(), scope, decision);
+            store.remembered_grants()
+        };
+        self.save_permission_grants(grants);
+    }
+
+    /// Allow a tool at the given scope. With `remember`, the grant is saved to
+    /// storage and restored by [`Core::new`]; otherwise it lasts for this
+    /// session only.
+    pub fn grant_permission(&mut self, tool_id: &str, scope: PermissionScope, remember: bool) {
+        let grants = {
+            let mut store = self.lock_permission_store();
+            store.grant(tool_id.to_string(), scope, remember);
+            store.remembered_grants()
+        };
+        self.save_permission_grants(grants);
+    }
+
+    /// Revoke any decision for a tool at the given scope, including a
+    /// remembered grant.
+    pub fn revoke_permission(&mut self, tool_id: &str, scope: &PermissionScope) {
+        let grants = {
+            let mut store = self.lock_permission_store();
+            store.clear_decision(tool_id, scope);
+            store.remembered_grants()
+        };
+        self.save_permission_grants(grants);
+    }
+
+    /// Set the highest tool risk level this workspace runs without asking.
+    ///
+    /// Tools above it require confirmation even when a permission grant
+    /// allows them.
+    pub fn set_max_auto_risk(&mut self, max_auto_risk: crate::tools::RiskLevel) {
+        self.lock_workspace().config.max_auto_risk = max_auto_risk;
+    }
+
+    /// Set the workspace system prompt passed to the LLM planner. `None` or a
+    /// blank prompt injects nothing.
+    pub fn set_system_prompt(&mut self, system_prompt: Option<String>) {
+        self.lock_workspace().config.system_prompt = system_prompt;
+    }
+
+    /// Configure when repeatedly failing tools are short-circuited.
+    pub fn set_breaker_config(&mut self, config: BreakerConfig) {
+        lock_recovering(&self.breaker, "breaker", &self.poisoned_locks).set_config(config);
+    }
+
+    /// Cap the LLM planner's tool-call iterations per command. `None` falls
+    /// back to the planner's default.
+    ///
+    /// Returns `InvalidInput` for `Some(0)`: the model is always called at
+    /// least once.
+    pub fn set_max_tool_iterations(&mut self, max_tool_iterations: Option<u32>) -> CoreResult<()> {
+        if max_tool_iterations == Some(0) {
+            return Err(CoreError::InvalidInput(
+                "max_tool_iterations must be at least 1".to_string(),
+            ));
+        }
+        self.lock_workspace().config.max_tool_iterations = max_tool_iterations;
+        Ok(())
     }
 
     /// Set a custom planner implementation.
@@ -472,12 +706,96 @@ impl Core {
         self.planner_label = label.into();
     }
 
+    /// Set how many top routed candidates contribute their app tools to the
+    /// planner's tool specs and LLM toolset. `0` limits the planner to
+    /// kernel tools.
+    pub fn set_candidate_tool_scope(&mut self, scope: usize) {
+        self.candidate_tool_scope = scope;
+    }
+
+    fn lock_workspace(&self) -> MutexGuard<'_, Workspace> {
+        lock_recovering(&self.workspace, "workspace", &self.poisoned_locks)
+    }
+
+    fn lock_registry(&self) -> MutexGuard<'_, ToolRegistry> {
+        lock_recovering(&self.registry, "registry", &self.poisoned_locks)
+    }
+
+    fn lock_permission_store(&self) -> MutexGuard<'_, PermissionStore> {
+        lock_recovering(&self.permission_store, "permission store", &self.poisoned_locks)
+    }
+
+    /// Lock storage, logging an `ErrorRaised` event for every lock recovered
+    /// from poisoning since the last call.
+    fn lock_storage(&self) -> MutexGuard<'_, Box<dyn Storage>> {
+        let mut storage = lock_recovering(&self.storage, "storage", &self.poisoned_locks);
+        report_recovered_locks(storage.as_mut(), &self.poisoned_locks);
+        storage
+    }
+
+    /// Roll the workspace back to the retained snapshot with the given
+    /// `sequence` id.
+    ///
+    /// Ephemeral state is sanitized as on startup. The current
+    /// `max_auto_risk` is kept, so a rollback never loosens the risk limit.
+    /// Returns the restored workspace, or `InvalidInput` if no such snapshot
+    /// is retained.
+    pub fn restore_snapshot(&mut self, sequence: u64) -> CoreResult<Workspace> {
+        let snapshot = self
+            .lock_storage()
+            .snapshots_mut()
+            .restore(sequence)
+            .ok_or_else(|| CoreError::InvalidInput(format!("no snapshot {sequence} retained")))?;
+        let mut restored: Workspace = serde_json::from_value(snapshot.data)
+            .map_err(|e| CoreError::Internal(format!("corrupt snapshot: {e}")))?;
+        Self::sanitize_restored(&mut restored);
+
+        let mut workspace = self.lock_workspace();
+        restored.config.max_auto_risk = workspace.config.max_auto_risk.clone();
+        *workspace = restored;
+        Ok(workspace.clone())
+    }
+
+    /// Build a permission store seeded with grants remembered in storage.
+    fn load_permission_store(storage: &dyn Storage) -> PermissionStore {
+        let mut store = PermissionStore::new();
+        let grants = storage
+            .kv()
+            .get(PERMISSIONS_NAMESPACE, REMEMBERED_GRANTS_KEY)
+            .and_then(|value| serde_json::from_value::<Vec<PermissionGrant>>(value).ok())
+            .unwrap_or_default();
+        store.restore_grants(grants);
+        store
+    }
+
+    fn save_permission_grants(&self, grants: Vec<PermissionGrant>) {
+        if let Ok(value) = serde_json::to_value(grants) {
+            self.lock_storage()
+                .kv_mut()
+                .set(PERMISSIONS_NAMESPACE, REMEMBERED_GRANTS_KEY, value);
+        }
+    }
+
+    /// Clear ephemeral state that must not survive a restart or rollback.
+    fn sanitize_restored(ws: &mut Workspace) {
+        ws.confirmation_pending = None;
+        if ws.mode == WorkspaceMode::AwaitingConfirmation {
+            ws.mode = WorkspaceMode::Idle;
+        }
+
+        // Expire follow-up if TTL has passed or turns exhausted.
+        let now = Self::now();
+        if ws.follow_up.is_some() && !ws.is_follow_up_valid(now) {
+            ws.follow_up = None;
+            if ws.mode == WorkspaceMode::FollowUpActive {
+                ws.mode = WorkspaceMode::Idle;
+            }
+        }
+    }
+
     /// Get the current unix timestamp in seconds.
     fn now() -> Timestamp {
-        std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs()
+        time::now_secs()
     }
 
     /// Check if the command text starts with a read-only verb.
@@ -488,9 +806,20 @@ impl Core {
             .any(|v| first_word.eq_ignore_ascii_case(v))
     }
 
-    fn collect_tool_specs(&self) -> Vec<ToolSpec> {
-        let registry = self.registry.lock().expect("registry lock");
-        registry
+    /// App ids of the top routed candidates whose tools are offered to the
+    /// planner.
+    fn scoped_instance_ids(&self, candidates: &[RouteCandidate]) -> Vec<String> {
+        candidates
+            .iter()
+            .take(self.candidate_tool_scope)
+            .map(|candidate| candidate.app_id.clone())
+            .collect()
+    }
+
+    /// Collect kernel tool specs plus the tools of each scoped instance.
+    fn collect_tool_specs(&self, instance_ids: &[String]) -> Vec<ToolSpec> {
+        let registry = self.lock_registry();
+        let mut specs: Vec<ToolSpec> = registry
             .kernel_tools()
             .into_iter()
             .map(|(id, def)| ToolSpec {
@@ -499,12 +828,41 @@ impl Core {
                 output_schema: def.output_schema.clone(),
                 risk_level: def.risk_level.clone(),
                 is_kernel: def.is_kernel,
+                instance_id: None,
             })
-            .collect()
+            .collect();
+
+        for instance_id in instance_ids {
+            specs.extend(
+                registry
+                    .instance_tools(instance_id)
+                    .into_iter()
+                    .map(|(id, def)| ToolSpec {
+                        tool_id: id.to_string(),
+                        input_schema: def.input_schema.clone(),
+                        output_schema: def.output_schema.clone(),
+                        risk_level: def.risk_level.clone(),
+                        is_kernel: def.is_kernel,
+                        instance_id: Some(instance_id.clone()),
+                    }),
+            );
+        }
+
+        specs
     }
 
-    fn run_planner(&self, input: PlannerInput) -> Result<PlannerOutput, PlannerError> {
-        let future = self.planner.plan(input);
+    fn run_planner(
+        &self,
+        input: PlannerInput,
+        on_text: Option<TextSink>,
+    ) -> Result<PlannerOutput, PlannerError> {
+        let planner = Arc::clone(&self.planner);
+        let future = async move {
+            match on_text {
+                Some(on_text) => planner.plan_streaming(input, on_text).await,
+                None => planner.plan(input).await,
+            }
+        };
         if let Ok(handle) = tokio::runtime::Handle::try_current() {
             handle.block_on(future)
         } else {
@@ -525,10 +883,11 @@ impl Core {
         if let Ok(data) = serde_json::to_value(&*workspace) {
             let snapshot = crate::storage::types::WorkspaceSnapshot {
                 session_id: workspace.session_id.clone(),
-                captured_at: SystemTime::now(),
+                sequence: 0,
+                captured_at: time::now(),
                 data,
             };
-            storage.snapshots_mut().save(snapshot);
+            storage.snapshots_mut().push(snapshot);
         }
     }
 
@@ -552,21 +911,29 @@ fn planner_error_message(output: &PlannerOutput) -> Option<String> {
         .and_then(|obj| obj.get("type"))
         .and_then(|value| value.as_str());
 
-    match error_type {
-        Some("tool_denied") => error
+    let reason = || {
+        error
             .as_object()
             .and_then(|obj| obj.get("reason"))
             .and_then(|value| value.as_str())
             .map(|value| value.to_string())
-            .or_else(|| Some("Tool execution denied.".to_string())),
-        Some("approval_required") => Some("Approval required.".to_string()),
-        _ => Some("Tool execution failed.".to_string()),
+    };
+
+    match error_type {
+        Some(TOOL_DENIED | TOOL_UNAVAILABLE) => {
+            reason().or_else(|| Some("Tool execution denied.".to_string()))
+        }
+        Some(INVALID_TOOL_CALL) => reason().or_else(|| Some("Invalid tool call.".to_string())),
+        Some(APPROVAL_REQUIRED) => Some("Approval required.".to_string()),
+        // TOOL_FAILED, and any error type this core does not know yet.
+        _ => reason().or_else(|| Some("Tool execution failed.".to_string())),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::SystemTime;
     use crate::routing::RoutingMetadata;
     use crate::storage::MemoryStorage;
     use crate::workspace::state::{
@@ -584,6 +951,7 @@ mod tests {
             examples: vec!["schedule a meeting".into(), "create an event".into()],
             verbs: vec!["schedule".into(), "create".into(), "cancel".into()],
             objects: vec!["meeting".into(), "event".into(), "appointment".into()],
+            weights: Default::default(),
         }
     }
 
@@ -594,6 +962,7 @@ mod tests {
             examples: vec!["show last note".into()],
             verbs: vec!["show".into(), "write".into(), "create".into()],
             objects: vec!["note".into(), "memo".into()],
+            weights: Default::default(),
         }
     }
 
@@ -795,7 +1164,7 @@ mod tests {
             ws.mode = WorkspaceMode::AwaitingConfirmation;
         }
 
-        let resp = core.confirm_action("confirm-abc", true).unwrap();
+        let resp = core.confirm_action("confirm-abc", true, false).unwrap();
         match resp {
             CoreResponse::Artifact { content, .. } => {
                 assert!(content.contains("confirmed"));
@@ -820,7 +1189,7 @@ mod tests {
             ws.mode = WorkspaceMode::AwaitingConfirmation;
         }
 
-        let resp = core.confirm_action("confirm-xyz", false).unwrap();
+        let resp = core.confirm_action("confirm-xyz", false, false).unwrap();
         match resp {
             CoreResponse::Artifact { content, .. } => {
                 assert!(content.contains("cancelled"));
@@ -843,7 +1212,7 @@ mod tests {
             });
         }
 
-        let resp = core.confirm_action("wrong-id", true).unwrap();
+        let resp = core.confirm_action("wrong-id", true, false).unwrap();
         match resp {
             CoreResponse::Error { message } => {
                 assert!(message.contains("wrong-id"));
@@ -856,7 +1225,7 @@ mod tests {
     fn confirm_action_no_pending_returns_error() {
         let mut core = Core::new(make_storage());
 
-        let resp = core.confirm_action("any-id", true).unwrap();
+        let resp = core.confirm_action("any-id", true, false).unwrap();
         match resp {
             CoreResponse::Error { message } => {
                 assert!(message.contains("No action pending"));
@@ -907,7 +1276,7 @@ mod tests {
             ws.mode = WorkspaceMode::AwaitingConfirmation;
         }
 
-        core.confirm_action("snap-confirm", true).unwrap();
+        core.confirm_action("snap-confirm", true, false).unwrap();
 
         let snapshot = core.storage().snapshots().load().unwrap();
         let restored: Workspace =
@@ -925,6 +1294,7 @@ mod tests {
         let data = serde_json::to_value(&ws).unwrap();
         storage.snapshots_mut().save(crate::storage::types::WorkspaceSnapshot {
             session_id: "restored-session".to_string(),
+            sequence: 0,
             captured_at: SystemTime::now(),
             data,
         });
@@ -947,6 +1317,7 @@ mod tests {
         let mut storage = MemoryStorage::new();
         storage.snapshots_mut().save(crate::storage::types::WorkspaceSnapshot {
             session_id: "bad-session".to_string(),
+            sequence: 0,
             captured_at: SystemTime::now(),
             data: serde_json::json!("not a workspace object"),
         });
@@ -976,6 +1347,7 @@ mod tests {
         let mut storage = MemoryStorage::new();
         storage.snapshots_mut().save(crate::storage::types::WorkspaceSnapshot {
             session_id: "confirm-session".to_string(),
+            sequence: 0,
             captured_at: SystemTime::now(),
             data: serde_json::to_value(&ws).unwrap(),
         });
@@ -1003,6 +1375,7 @@ mod tests {
         let mut storage = MemoryStorage::new();
         storage.snapshots_mut().save(crate::storage::types::WorkspaceSnapshot {
             session_id: "followup-session".to_string(),
+            sequence: 0,
             captured_at: SystemTime::now(),
             data: serde_json::to_value(&ws).unwrap(),
         });
@@ -1036,6 +1409,7 @@ mod tests {
         let mut storage = MemoryStorage::new();
         storage.snapshots_mut().save(crate::storage::types::WorkspaceSnapshot {
             session_id: "valid-followup".to_string(),
+            sequence: 0,
             captured_at: SystemTime::now(),
             data: serde_json::to_value(&ws).unwrap(),
         });
@@ -1060,10 +1434,90 @@ mod tests {
         core.submit_command("create an event").unwrap();
         let snap2 = core.storage().snapshots().load().unwrap();
 
-        // Snapshot is overwritten each time (single-slot semantics).
+        // The current slot is overwritten each time; history keeps both.
         assert_eq!(snap1.session_id, snap2.session_id);
         // captured_at may differ but data reflects latest state.
         assert!(snap2.captured_at >= snap1.captured_at);
+        assert_eq!(core.storage().snapshots().history().len(), 2);
+    }
+
+    #[test]
+    fn restore_snapshot_rolls_back_workspace() {
+        let mut core = Core::new(make_storage());
+        core.router_mut().register(calendar_metadata());
+
+        let clock = time::MockClock::at_secs(1_000_000);
+        core.submit_command("schedule a meeting").unwrap();
+        let before = core.storage().snapshots().load().unwrap();
+
+        clock.advance_secs(1);
+        core.workspace_mut().focus = Some("calendar".to_string());
+        core.submit_command("create an event").unwrap();
+        assert_eq!(core.workspace().focus.as_deref(), Some("calendar"));
+
+        let restored = core.restore_snapshot(before.sequence).unwrap();
+        assert_eq!(restored.focus, None);
+        assert_eq!(core.workspace().focus, None);
+        let current = core.storage().snapshots().load().unwrap();
+        assert_eq!(current.sequence, before.sequence);
+        assert_eq!(current.captured_at, before.captured_at);
+    }
+
+    #[test]
+    fn restore_snapshot_keeps_current_max_auto_risk() {
+        let mut core = Core::new(make_storage());
+        core.router_mut().register(calendar_metadata());
+
+        let clock = time::MockClock::at_secs(1_000_000);
+        core.submit_command("schedule a meeting").unwrap();
+        let before = core.storage().snapshots().load().unwrap();
+
+        clock.advance_secs(1);
+        core.set_max_auto_risk(crate::tools::RiskLevel::Safe);
+        core.submit_command("create an event").unwrap();
+
+        let restored = core.restore_snapshot(before.sequence).unwrap();
+        assert_eq!(restored.config.max_auto_risk, crate::tools::RiskLevel::Safe);
+        assert_eq!(core.workspace().config.max_auto_risk, crate::tools::RiskLevel::Safe);
+    }
+
+    #[test]
+    fn clipboard_history_records_only_while_enabled() {
+        let provider = Arc::new(crate::platform::MockClipboardProvider::new(vec![
+            serde_json::json!({"text": "copied"}),
+        ]));
+        let mut core = Core::new(make_storage());
+        core.register_builtins_with(
+            provider,
+            Arc::new(crate::platform::MockAppsProvider::new(vec![], vec![])),
+        );
+        assert!(!core.clipboard_history_enabled());
+        assert!(core.storage().clipboard().is_empty());
+
+        core.enable_clipboard_history(Duration::from_secs(60));
+        assert!(core.clipboard_history_enabled());
+        core.disable_clipboard_history();
+        assert!(!core.clipboard_history_enabled());
+
+        // The recorder polls once on start, before it can be stopped.
+        assert_eq!(core.storage().clipboard().latest().unwrap().content, "copied");
+    }
+
+    #[test]
+    fn zero_max_tool_iterations_is_rejected() {
+        let mut core = Core::new(make_storage());
+        core.set_max_tool_iterations(Some(4)).unwrap();
+
+        let result = core.set_max_tool_iterations(Some(0));
+        assert!(matches!(result, Err(CoreError::InvalidInput(_))));
+        assert_eq!(core.workspace().config.max_tool_iterations, Some(4));
+    }
+
+    #[test]
+    fn restore_snapshot_unknown_sequence_is_invalid_input() {
+        let mut core = Core::new(make_storage());
+        let result = core.restore_snapshot(42);
+        assert!(matches!(result, Err(CoreError::InvalidInput(_))));
     }
 
     #[test]
@@ -1107,6 +1561,480 @@ mod tests {
         assert_eq!(actions[1].description, "Command (5 chars)");
     }
 
+    #[test]
+    fn subscribe_actions_streams_new_summaries() {
+        let mut core = Core::new(make_storage());
+        core.router_mut().register(calendar_metadata());
+        core.submit_command("schedule a meeting").unwrap();
+
+        let mut actions = core.subscribe_actions(10);
+        let recent = actions.try_recv().unwrap();
+        assert_eq!(recent.description, "Command (18 chars)");
+        assert!(actions.try_recv().is_none());
+
+        core.submit_command("create an event").unwrap();
+        let live = actions.try_recv().unwrap();
+        // Live summaries carry char counts, never the raw text.
+        assert_eq!(live.description, "Command (15 chars)");
+        assert!(!live.description.contains("event"));
+        assert!(actions.try_recv().is_none());
+    }
+
+    #[test]
+    fn tool_catalog_lists_registered_builtins() {
+        let mut core = Core::new(make_storage());
+        assert!(core.tool_catalog().is_empty());
+
+        core.register_builtins();
+        let catalog = core.tool_catalog();
+        assert!(catalog.iter().any(|t| t.tool_id == "notes.create"));
+        assert!(catalog.iter().all(|t| t.instance_id.is_none()));
+    }
+
+    #[test]
+    fn active_tool_catalog_skips_inactive_instances() {
+        let mut core = Core::new(make_storage());
+        {
+            let mut registry = core.registry_mut();
+            registry.register_instance_tool("calendar".to_string(), instance_tool("calendar.create_event"));
+            registry.register_instance_tool("notes".to_string(), instance_tool("notes.archive"));
+        }
+        for (id, status) in [
+            ("calendar", ApplicationStatus::Active),
+            ("notes", ApplicationStatus::Inactive),
+        ] {
+            core.workspace_mut().instances.insert(
+                id.to_string(),
+                crate::workspace::ApplicationInstance {
+                    instance_id: id.to_string(),
+                    app_id: id.to_string(),
+                    status,
+                    context: Default::default(),
+                    mounted_tools: vec![],
+                },
+            );
+        }
+
+        assert_eq!(core.tool_catalog().len(), 2);
+        let active: Vec<_> = core.active_tool_catalog().into_iter().map(|t| t.tool_id).collect();
+        assert_eq!(active, vec!["calendar.create_event"]);
+    }
+
+    struct RecordingPlanner {
+        tools: Arc<Mutex<Vec<ToolSpec>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Planner for RecordingPlanner {
+        async fn plan(&self, input: PlannerInput) -> Result<PlannerOutput, PlannerError> {
+            *self.tools.lock().unwrap() = input.tools.clone();
+            StubPlanner.plan(input).await
+        }
+    }
+
+    fn instance_tool(id: &str) -> crate::tools::ToolDefinition {
+        crate::tools::ToolDefinition {
+            tool_id: id.to_string(),
+            input_schema: serde_json::json!({}),
+            output_schema: serde_json::json!({}),
+            risk_level: crate::tools::RiskLevel::Safe,
+            is_kernel: false,
+            handler: Box::new(|_args, _ctx| Ok(serde_json::json!(null))),
+        }
+    }
+
+    fn core_with_recording_planner() -> (Core, Arc<Mutex<Vec<ToolSpec>>>) {
+        let mut core = Core::new(make_storage());
+        core.router_mut().register(calendar_metadata());
+        core.router_mut().register(notes_metadata());
+        {
+            let mut registry = core.registry_mut();
+            registry.register_instance_tool("calendar".to_string(), instance_tool("calendar.create_event"));
+            registry.register_instance_tool("notes".to_string(), instance_tool("notes.archive"));
+        }
+        let tools = Arc::new(Mutex::new(Vec::new()));
+        core.set_planner(Arc::new(RecordingPlanner {
+            tools: Arc::clone(&tools),
+        }));
+        (core, tools)
+    }
+
+    #[test]
+    fn planner_receives_tools_of_routed_candidates() {
+        let (mut core, tools) = core_with_recording_planner();
+
+        core.submit_command("schedule a meeting").unwrap();
+
+        let tools = tools.lock().unwrap();
+        let calendar_tool = tools
+            .iter()
+            .find(|t| t.tool_id == "calendar.create_event")
+            .expect("calendar tool offered to planner");
+        assert_eq!(calendar_tool.instance_id.as_deref(), Some("calendar"));
+        // Apps that were not routed to do not contribute tools.
+        assert!(!tools.iter().any(|t| t.tool_id == "notes.archive"));
+    }
+
+    #[test]
+    fn candidate_tool_scope_zero_offers_only_kernel_tools() {
+        let (mut core, tools) = core_with_recording_planner();
+        core.set_candidate_tool_scope(0);
+
+        core.submit_command("schedule a meeting").unwrap();
+
+        assert!(tools.lock().unwrap().iter().all(|t| t.instance_id.is_none()));
+    }
+
+    struct ChunkedPlanner;
+
+    #[async_trait::async_trait]
+    impl Planner for ChunkedPlanner {
+        async fn plan(&self, _input: PlannerInput) -> Result<PlannerOutput, PlannerError> {
+            Ok(PlannerOutput::new(
+                crate::planner::Plan::empty(),
+                crate::planner::PlanMetadata::stub(),
+                Some("Meeting scheduled.".to_string()),
+                vec![],
+            ))
+        }
+
+        async fn plan_streaming(
+            &self,
+            input: PlannerInput,
+            on_text: TextSink,
+        ) -> Result<PlannerOutput, PlannerError> {
+            on_text("Meeting ");
+            on_text("scheduled.");
+            self.plan(input).await
+        }
+    }
+
+    #[test]
+    fn submit_command_stream_sends_text_then_one_terminal() {
+        let mut core = Core::new(make_storage());
+        core.router_mut().register(calendar_metadata());
+        core.set_planner(Arc::new(ChunkedPlanner));
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        core.submit_command_stream("schedule a meeting", sender).unwrap();
+        let deltas: Vec<CoreResponseDelta> = receiver.iter().collect();
+
+        assert_eq!(deltas.len(), 3);
+        assert!(matches!(&deltas[0], CoreResponseDelta::Text { chunk } if chunk == "Meeting "));
+        assert!(matches!(&deltas[1], CoreResponseDelta::Text { chunk } if chunk == "scheduled."));
+        match &deltas[2] {
+            CoreResponseDelta::Done {
+                response: CoreResponse::Artifact { content, .. },
+            } => assert_eq!(content, "Meeting scheduled."),
+            other => panic!("expected terminal artifact, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn submit_command_stream_without_planner_text_sends_only_terminal() {
+        let mut core = Core::new(make_storage());
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        core.submit_command_stream("xyzzy", sender).unwrap();
+        let deltas: Vec<CoreResponseDelta> = receiver.iter().collect();
+
+        assert_eq!(deltas.len(), 1);
+        assert!(matches!(deltas[0], CoreResponseDelta::Done { .. }));
+    }
+
+    #[test]
+    fn core_response_delta_serializable() {
+        let delta = CoreResponseDelta::Done {
+            response: CoreResponse::Error {
+                message: "boom".to_string(),
+            },
+        };
+        let json = serde_json::to_string(&delta).unwrap();
+        assert!(json.contains("\"type\":\"Done\""));
+        let back: CoreResponseDelta = serde_json::from_str(&json).unwrap();
+        assert!(matches!(back, CoreResponseDelta::Done { .. }));
+    }
+
+    #[test]
+    fn poisoned_workspace_lock_is_recovered() {
+        let mut core = Core::new(make_storage());
+        core.router_mut().register(calendar_metadata());
+
+        let workspace = Arc::clone(&core.workspace);
+        let result = std::thread::spawn(move || {
+            let _guard = workspace.lock().unwrap();
+            panic!("panic while holding workspace lock");
+        })
+        .join();
+        assert!(result.is_err());
+        assert!(core.workspace.is_poisoned());
+
+        core.submit_command("schedule a meeting").unwrap();
+        core.submit_command("schedule a meeting").unwrap();
+
+        assert!(!core.workspace.is_poisoned());
+        let storage = core.storage();
+        let recovered: Vec<_> = storage
+            .event_log()
+            .tail(100)
+            .into_iter()
+            .filter(|record| {
+                matches!(&record.event, Event::ErrorRaised { code, .. } if code == "lock_poisoned")
+            })
+            .collect();
+        assert_eq!(recovered.len(), 1);
+    }
+
+    #[test]
+    fn follow_up_expires_after_ttl_with_mock_clock() {
+        let clock = time::MockClock::at_secs(1_000_000);
+        let mut core = Core::new(make_storage());
+        core.router_mut().register(calendar_metadata());
+        core.activate_follow_up(
+            "schedule a meeting".to_string(),
+            vec![],
+            "calendar".to_string(),
+        );
+
+        clock.advance_secs(crate::workspace::FOLLOW_UP_TTL_SECS - 1);
+        let resp = core.submit_command("make it 2:30").unwrap();
+        assert!(!matches!(resp, CoreResponse::Error { .. }));
+
+        clock.advance_secs(1);
+        match core.submit_command("make it 3:00").unwrap() {
+            CoreResponse::Error { message } => assert!(message.contains("Follow-up expired")),
+            other => panic!("expected expiry error, got {:?}", other),
+        }
+    }
+
+    /// Planner that executes one tool through the core's runtime, reporting
+    /// permission outcomes as tool errors like the LLM planner does.
+    struct ExecutingPlanner {
+        runtime: Arc<ToolRuntime>,
+        tool_id: String,
+    }
+
+    #[async_trait::async_trait]
+    impl Planner for ExecutingPlanner {
+        async fn plan(&self, _input: PlannerInput) -> Result<PlannerOutput, PlannerError> {
+            let registry = self.runtime.registry.lock().unwrap();
+            let mut workspace = self.runtime.workspace.lock().unwrap();
+            let mut storage = self.runtime.storage.lock().unwrap();
+            let permission_store = self.runtime.permission_store.lock().unwrap();
+            let (event_log, clipboard_store) = storage.split_event_clipboard_mut();
+            let outcome = crate::tools::execute_tool(
+                &registry,
+                &mut workspace,
+                event_log,
+                clipboard_store,
+                &permission_store,
+                &self.runtime.instance_id,
+                &self.tool_id,
+                serde_json::json!({}),
+                Uuid::new_v4(),
+            );
+            let tool_errors = outcome.into_result(&self.tool_id).err().into_iter().collect();
+            Ok(PlannerOutput::new(
+                crate::planner::Plan::empty(),
+                crate::planner::PlanMetadata::stub(),
+                None,
+                tool_errors,
+            ))
+        }
+    }
+
+    fn core_executing(risk_level: crate::tools::RiskLevel) -> Core {
+        let mut tool = instance_tool("calendar.cancel");
+        tool.risk_level = risk_level;
+        core_executing_tool(tool)
+    }
+
+    fn core_executing_tool(mut tool: crate::tools::ToolDefinition) -> Core {
+        let mut core = Core::new(make_storage());
+        core.router_mut().register(calendar_metadata());
+        tool.is_kernel = true;
+        core.registry_mut().register_kernel_tool(tool);
+        let runtime = core.tool_runtime("kernel");
+        core.set_planner(Arc::new(ExecutingPlanner {
+            runtime,
+            tool_id: "calendar.cancel".to_string(),
+        }));
+        core
+    }
+
+    #[test]
+    fn failing_tool_is_reported_as_failure_not_denial() {
+        let mut tool = instance_tool("calendar.cancel");
+        tool.handler = Box::new(|_args, _ctx| Err(CoreError::Internal("calendar offline".to_string())));
+        let mut core = core_executing_tool(tool);
+
+        match core.submit_command("schedule a meeting").unwrap() {
+            CoreResponse::Error { message } => {
+                assert!(message.contains("failed: "));
+                assert!(message.contains("calendar offline"));
+                assert!(!message.contains("denied"));
+            }
+            other => panic!("expected Error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn denied_tool_surfaces_reason_as_error() {
+        let mut core = core_executing(crate::tools::RiskLevel::Confirm);
+        core.set_permission_decision(
+            "calendar.cancel",
+            PermissionScope::Write,
+            PermissionDecision::Deny,
+        );
+
+        match core.submit_command("schedule a meeting").unwrap() {
+            CoreResponse::Error { message } => assert!(message.contains("denied by stored permission")),
+            other => panic!("expected Error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn approval_required_tool_surfaces_confirmation() {
+        let mut core = core_executing(crate::tools::RiskLevel::Destructive);
+
+        match core.submit_command("schedule a meeting").unwrap() {
+            CoreResponse::Confirmation { confirmation_id, .. } => {
+                assert!(confirmation_id.ends_with("calendar.cancel"));
+            }
+            other => panic!("expected Confirmation, got {:?}", other),
+        }
+    }
+
+    /// Copy remembered grants into a fresh storage, simulating a restart.
+    fn restart_storage(core: &Core) -> Box<dyn Storage> {
+        let mut storage = make_storage();
+        if let Some(grants) = core
+            .storage()
+            .kv()
+            .get(PERMISSIONS_NAMESPACE, REMEMBERED_GRANTS_KEY)
+        {
+            storage
+                .kv_mut()
+                .set(PERMISSIONS_NAMESPACE, REMEMBERED_GRANTS_KEY, grants);
+        }
+        storage
+    }
+
+    #[test]
+    fn remembered_grant_survives_restart() {
+        let mut core = Core::new(make_storage());
+        core.grant_permission("notes.delete", PermissionScope::Execute, true);
+        core.grant_permission("apps.action", PermissionScope::Write, false);
+
+        let mut restarted = Core::new(restart_storage(&core));
+        let store = restarted.permission_store_mut();
+        assert_eq!(
+            store.get_decision("notes.delete", &PermissionScope::Execute),
+            Some(&PermissionDecision::Allow)
+        );
+        assert_eq!(store.get_decision("apps.action", &PermissionScope::Write), None);
+    }
+
+    #[test]
+    fn revoked_grant_does_not_survive_restart() {
+        let mut core = Core::new(make_storage());
+        core.grant_permission("notes.delete", PermissionScope::Execute, true);
+        core.revoke_permission("notes.delete", &PermissionScope::Execute);
+
+        let mut restarted = Core::new(restart_storage(&core));
+        assert_eq!(
+            restarted
+                .permission_store_mut()
+                .get_decision("notes.delete", &PermissionScope::Execute),
+            None
+        );
+    }
+
+    #[test]
+    fn denied_decision_replaces_remembered_grant_after_restart() {
+        let mut core = Core::new(make_storage());
+        core.grant_permission("notes.delete", PermissionScope::Execute, true);
+        core.set_permission_decision("notes.delete", PermissionScope::Execute, PermissionDecision::Deny);
+
+        let mut restarted = Core::new(restart_storage(&core));
+        assert_eq!(
+            restarted
+                .permission_store_mut()
+                .get_decision("notes.delete", &PermissionScope::Execute),
+            None
+        );
+    }
+
+    #[test]
+    fn confirm_with_remember_grants_tool_across_restart() {
+        let mut core = core_executing(crate::tools::RiskLevel::Confirm);
+        let confirmation_id = match core.submit_command("schedule a meeting").unwrap() {
+            CoreResponse::Confirmation { confirmation_id, .. } => confirmation_id,
+            other => panic!("expected Confirmation, got {:?}", other),
+        };
+        core.confirm_action(&confirmation_id, true, true).unwrap();
+
+        let mut restarted = Core::new(restart_storage(&core));
+        assert_eq!(
+            restarted
+                .permission_store_mut()
+                .get_decision("calendar.cancel", &PermissionScope::Write),
+            Some(&PermissionDecision::Allow)
+        );
+    }
+
+    #[test]
+    fn risk_above_workspace_limit_requires_confirmation_despite_grant() {
+        let mut core = core_executing(crate::tools::RiskLevel::Destructive);
+        core.grant_permission("calendar.cancel", PermissionScope::Execute, false);
+        core.set_max_auto_risk(crate::tools::RiskLevel::Safe);
+
+        let resp = core.submit_command("schedule a meeting").unwrap();
+        assert!(matches!(resp, CoreResponse::Confirmation { .. }));
+    }
+
+    #[test]
+    fn risk_within_workspace_limit_runs_without_prompt() {
+        let mut core = core_executing(crate::tools::RiskLevel::Safe);
+        core.set_max_auto_risk(crate::tools::RiskLevel::Safe);
+
+        let resp = core.submit_command("schedule a meeting").unwrap();
+        assert!(!matches!(
+            resp,
+            CoreResponse::Confirmation { .. } | CoreResponse::Error { .. }
+        ));
+        assert!(core.workspace().confirmation_pending.is_none());
+    }
+
+    struct PromptRecordingPlanner {
+        prompt: Arc<Mutex<Option<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Planner for PromptRecordingPlanner {
+        async fn plan(&self, input: PlannerInput) -> Result<PlannerOutput, PlannerError> {
+            *self.prompt.lock().unwrap() = input.workspace.config.system_prompt.clone();
+            StubPlanner.plan(input).await
+        }
+    }
+
+    #[test]
+    fn system_prompt_reaches_planner_input() {
+        let mut core = Core::new(make_storage());
+        core.router_mut().register(calendar_metadata());
+        let prompt = Arc::new(Mutex::new(None));
+        core.set_planner(Arc::new(PromptRecordingPlanner {
+            prompt: prompt.clone(),
+        }));
+        core.set_system_prompt(Some("Answer in one sentence.".to_string()));
+
+        core.submit_command("schedule a meeting").unwrap();
+        assert_eq!(
+            prompt.lock().unwrap().as_deref(),
+            Some("Answer in one sentence.")
+        );
+    }
+
     // --- Serde serialization tests (required by Core-12 test checklist) ---
 
     #[test]
@@ -106,6 +106,7 @@ impl ExtensionManager {
         &mut self,
         extension_id: &str,
         registry: &mut ToolRegistry,
+        router: &mut Router,
     ) -> CoreResult<()> {
         let ext = self.extensions.remove(extension_id).ok_or_else(|| {
             CoreError::InvalidInput(format!("extension not loaded: {extension_id}"))
@@ -113,6 +114,7 @@ impl ExtensionManager {
 
         // Remove instance tools for this extension
         registry.remove_instance_tools(extension_id);
+        router.unregister(extension_id);
 
         // Kill the host process
         drop(ext);
@@ -265,6 +267,7 @@ impl ExtensionManager {
             examples: manifest.routing.examples.clone(),
             verbs: manifest.routing.verbs.clone(),
             objects: manifest.routing.objects.clone(),
+            weights: manifest.routing.weights,
         };
         router.register(metadata);
     }
@@ -499,6 +502,8 @@ mod tests {
             raw_text: "create a ticket".to_string(),
             normalized_text: "create a ticket".to_string(),
             tags: vec![],
+            tokens: vec![],
+            flags: Default::default(),
         };
         let result = router.route(&cmd);
         assert!(!result.candidates.is_empty());
@@ -552,6 +557,8 @@ mod tests {
             raw_text: "create a ticket".to_string(),
             normalized_text: "create a ticket".to_string(),
             tags: vec![],
+            tokens: vec![],
+            flags: Default::default(),
         };
         let routing_result = router.route(&cmd);
         assert!(
@@ -581,10 +588,11 @@ mod tests {
         assert_eq!(result["status"], "open");
 
         // Unload the extension
-        mgr.unload_extension("my-app", &mut registry)
+        mgr.unload_extension("my-app", &mut registry, &mut router)
             .expect("should unload");
         assert!(mgr.loaded_extensions().is_empty());
         assert!(registry.lookup("my-app", "my_app.create_ticket").is_none());
+        assert!(router.route(&cmd).candidates.iter().all(|c| c.app_id != "my-app"));
     }
 
     /// Timeout test: host hangs and invocation times out.
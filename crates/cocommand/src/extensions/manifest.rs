@@ -5,6 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::routing::RoutingWeights;
 use crate::tools::schema::RiskLevel;
 
 /// Extension manifest (v0).
@@ -43,6 +44,9 @@ pub struct ExtensionRouting {
     /// Objects/nouns (e.g., ["ticket", "issue"]).
     #[serde(default)]
     pub objects: Vec<String>,
+    /// Optional per-signal score weights (e.g., {"keyword": 5.0}).
+    #[serde(default)]
+    pub weights: RoutingWeights,
 }
 
 /// Tool definition declared in the extension manifest.
@@ -130,6 +134,25 @@ mod tests {
         assert!(manifest.routing.keywords.is_empty());
     }
 
+    #[test]
+    fn partial_routing_weights_fall_back_to_defaults() {
+        let manifest_json = json!({
+            "id": "launcher",
+            "name": "Launcher",
+            "description": "Keyword-heavy routing",
+            "entrypoint": "main.ts",
+            "routing": {
+                "keywords": ["launch"],
+                "weights": {"keyword": 5.0}
+            }
+        });
+
+        let manifest: ExtensionManifest =
+            serde_json::from_value(manifest_json).expect("should deserialize");
+        assert_eq!(manifest.routing.weights.keyword, 5.0);
+        assert_eq!(manifest.routing.weights.verb, RoutingWeights::default().verb);
+    }
+
     #[test]
     fn manifest_roundtrip() {
         let manifest = ExtensionManifest {
@@ -142,6 +165,7 @@ mod tests {
                 examples: vec![],
                 verbs: vec![],
                 objects: vec![],
+                weights: Default::default(),
             },
             tools: vec![ExtensionToolDef {
                 id: "test.tool".to_string(),
@@ -15,13 +15,14 @@ pub mod events;
 pub mod storage;
 pub mod extensions;
 pub mod builtins;
+pub mod utils;
 pub mod llm;
 
 pub use crate::core::Core;
 pub use crate::error::{CoreError, CoreResult};
 pub use crate::planner::LlmPlanner;
 pub use crate::types::{
-    ActionSummary, ArtifactAction, ConfirmActionRequest, CoreResponse, RoutedCandidate,
-    SubmitCommandRequest,
+    ActionSubscription, ActionSummary, ArtifactAction, ConfirmActionRequest, CoreResponse,
+    CoreResponseDelta, RoutedCandidate, SubmitCommandRequest,
 };
 pub use crate::workspace::Workspace;
@@ -1,3 +1,3 @@
 pub mod tool_adapter;
 
-pub use tool_adapter::{build_toolset, ToolRuntime};
+pub use tool_adapter::{build_toolset, build_toolset_with_instances, ToolRuntime};
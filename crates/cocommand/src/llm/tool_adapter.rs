@@ -4,13 +4,15 @@ use std::sync::{Arc, Mutex};
 
 use llm_kit_core::tool::ToolSet;
 use llm_kit_provider_utils::tool::{Tool, ToolExecuteOptions, ToolExecutionOutput};
-use serde_json::json;
 use uuid::Uuid;
 
 use crate::permissions::PermissionStore;
 use crate::storage::Storage;
-use crate::tools::executor::{execute_tool, ToolExecutionOutcome};
+use crate::tools::breaker::CircuitBreaker;
+use crate::tools::executor::{execute_tool, ToolExecutionOutcome, TOOL_UNAVAILABLE};
 use crate::tools::registry::ToolRegistry;
+use crate::tools::schema::ToolDefinition;
+use crate::utils::sync::{lock_recovering, report_recovered_locks, PoisonedLocks};
 use crate::workspace::Workspace;
 
 /// Shared runtime state required to execute tools via llm-kit.
@@ -19,90 +21,162 @@ pub struct ToolRuntime {
     pub workspace: Arc<Mutex<Workspace>>,
     pub storage: Arc<Mutex<Box<dyn Storage>>>,
     pub permission_store: Arc<Mutex<PermissionStore>>,
+    pub breaker: Arc<Mutex<CircuitBreaker>>,
+    /// Locks recovered from poisoning; shared with `Core` so they are logged.
+    pub poisoned_locks: Arc<PoisonedLocks>,
     pub instance_id: String,
 }
 
+/// A registry tool as exposed to the LLM.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ToolDescriptor {
+    /// Sanitized name the model calls the tool by.
+    pub name: String,
+    pub tool_id: String,
+    /// Instance the tool is executed under.
+    pub instance_id: String,
+    pub input_schema: serde_json::Value,
+    pub output_schema: serde_json::Value,
+}
+
 /// Build a llm-kit ToolSet backed by the cocommand ToolRegistry.
+///
+/// Only kernel tools are included; see [`build_toolset_with_instances`].
 pub fn build_toolset(runtime: Arc<ToolRuntime>) -> ToolSet {
+    build_toolset_with_instances(runtime, &[])
+}
+
+/// Build a llm-kit ToolSet with the kernel tools plus the tools registered
+/// for each of `instance_ids` (typically the top routed candidates).
+///
+/// Instance tools run under their owning instance, not the runtime's.
+pub fn build_toolset_with_instances(runtime: Arc<ToolRuntime>, instance_ids: &[String]) -> ToolSet {
     let descriptors = {
-        let registry = runtime.registry.lock().expect("registry lock");
-        registry
-            .kernel_tools()
-            .into_iter()
-            .map(|(id, def)| {
-                (
-                    id.to_string(),
-                    def.input_schema.clone(),
-                    def.output_schema.clone(),
-                )
-            })
-            .collect::<Vec<_>>()
+        let registry = lock_recovering(&runtime.registry, "registry", &runtime.poisoned_locks);
+        tool_descriptors(&registry, &runtime.instance_id, instance_ids)
     };
 
     let mut tools = ToolSet::new();
-    let mut used_names = HashSet::new();
 
-    for (tool_id, input_schema, output_schema) in descriptors {
+    for descriptor in descriptors {
         let runtime = Arc::clone(&runtime);
-        let tool_id_for_exec = tool_id.clone();
-        let safe_tool_name = sanitize_tool_name(&tool_id, &mut used_names);
-        let input_schema = normalize_input_schema(input_schema);
+        let tool_id = descriptor.tool_id.clone();
+        let instance_id = descriptor.instance_id.clone();
+        let input_schema = normalize_input_schema(descriptor.input_schema);
 
         let tool = Tool::function(input_schema)
-            .with_output_schema(output_schema)
+            .with_output_schema(descriptor.output_schema)
             .with_execute(Arc::new(move |input, options: ToolExecuteOptions| {
                 let runtime = Arc::clone(&runtime);
-                let tool_id = tool_id_for_exec.clone();
+                let tool_id = tool_id.clone();
+                let instance_id = instance_id.clone();
 
                 ToolExecutionOutput::Single(Box::pin(async move {
                     let tool_call_id = Uuid::parse_str(&options.tool_call_id)
                         .unwrap_or_else(|_| Uuid::new_v4());
-
-                    let registry = runtime.registry.lock().expect("registry lock");
-                    let mut workspace = runtime.workspace.lock().expect("workspace lock");
-                    let mut storage = runtime.storage.lock().expect("storage lock");
-                    let permission_store =
-                        runtime.permission_store.lock().expect("permission store lock");
-                    let (event_log, clipboard_store) = storage.split_event_clipboard_mut();
-
-                    match execute_tool(
-                        &registry,
-                        &mut workspace,
-                        event_log,
-                        clipboard_store,
-                        &permission_store,
-                        &runtime.instance_id,
-                        &tool_id,
-                        input,
-                        tool_call_id,
-                    ) {
-                        ToolExecutionOutcome::Executed(exec) => Ok(exec.result),
-                        ToolExecutionOutcome::NeedsConfirmation { confirmation_id } => Err(json!({
-                            "type": "approval_required",
-                            "confirmation_id": confirmation_id,
-                            "tool_id": tool_id,
-                        })),
-                        ToolExecutionOutcome::Denied { reason, .. } => Err(json!({
-                            "type": "tool_denied",
-                            "tool_id": tool_id,
-                            "reason": reason,
-                        })),
-                    }
+                    run_tool(&runtime, &instance_id, &tool_id, input, tool_call_id)
                 }))
             }));
 
-        if safe_tool_name != tool_id {
+        if descriptor.name != descriptor.tool_id {
             println!(
                 "[planner] tool_name_mapped original={} safe={}",
-                tool_id, safe_tool_name
+                descriptor.tool_id, descriptor.name
             );
         }
-        tools.insert(safe_tool_name, tool);
+        tools.insert(descriptor.name, tool);
     }
 
     tools
 }
 
+/// List the tools to expose: kernel tools sorted by id, then each
+/// instance's tools in the order given. Duplicate instance ids are skipped.
+pub(crate) fn tool_descriptors(
+    registry: &ToolRegistry,
+    kernel_instance_id: &str,
+    instance_ids: &[String],
+) -> Vec<ToolDescriptor> {
+    let mut kernel = registry.kernel_tools();
+    kernel.sort_by(|a, b| a.0.cmp(b.0));
+    let mut entries: Vec<(&str, &ToolDefinition, &str)> = kernel
+        .into_iter()
+        .map(|(id, def)| (id, def, kernel_instance_id))
+        .collect();
+
+    let mut seen = HashSet::new();
+    for instance_id in instance_ids {
+        if !seen.insert(instance_id.as_str()) {
+            continue;
+        }
+        entries.extend(
+            registry
+                .instance_tools(instance_id)
+                .into_iter()
+                .map(|(id, def)| (id, def, instance_id.as_str())),
+        );
+    }
+
+    let mut used_names = HashSet::new();
+    entries
+        .into_iter()
+        .map(|(tool_id, def, instance_id)| ToolDescriptor {
+            name: sanitize_tool_name(tool_id, &mut used_names),
+            tool_id: tool_id.to_string(),
+            instance_id: instance_id.to_string(),
+            input_schema: def.input_schema.clone(),
+            output_schema: def.output_schema.clone(),
+        })
+        .collect()
+}
+
+/// Execute one LLM tool call through the circuit breaker and the executor.
+fn run_tool(
+    runtime: &ToolRuntime,
+    instance_id: &str,
+    tool_id: &str,
+    input: serde_json::Value,
+    tool_call_id: Uuid,
+) -> Result<serde_json::Value, serde_json::Value> {
+    let poisoned = &runtime.poisoned_locks;
+    let mut breaker = lock_recovering(&runtime.breaker, "breaker", poisoned);
+    if let Err(retry_after_secs) = breaker.check(tool_id) {
+        return Err(serde_json::json!({
+            "type": TOOL_UNAVAILABLE,
+            "tool_id": tool_id,
+            "reason": format!("tool '{tool_id}' temporarily unavailable"),
+            "retry_after_secs": retry_after_secs,
+        }));
+    }
+
+    let registry = lock_recovering(&runtime.registry, "registry", poisoned);
+    let mut workspace = lock_recovering(&runtime.workspace, "workspace", poisoned);
+    let permission_store = lock_recovering(&runtime.permission_store, "permission store", poisoned);
+    let mut storage = lock_recovering(&runtime.storage, "storage", poisoned);
+    report_recovered_locks(storage.as_mut(), poisoned);
+    let (event_log, clipboard_store) = storage.split_event_clipboard_mut();
+
+    let outcome = execute_tool(
+        &registry,
+        &mut workspace,
+        event_log,
+        clipboard_store,
+        &permission_store,
+        instance_id,
+        tool_id,
+        input,
+        tool_call_id,
+    );
+    if outcome.is_handler_failure() {
+        if breaker.record_failure(tool_id) {
+            println!("[planner] circuit_breaker_open tool={tool_id}");
+        }
+    } else if matches!(outcome, ToolExecutionOutcome::Executed(_)) {
+        breaker.record_success(tool_id);
+    }
+    outcome.into_result(tool_id)
+}
+
 fn normalize_input_schema(schema: serde_json::Value) -> serde_json::Value {
     let mut obj = match schema.as_object() {
         Some(map) => map.clone(),
@@ -157,3 +231,112 @@ fn short_hash_suffix(value: &str) -> String {
         .take(8)
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::Event;
+    use crate::storage::MemoryStorage;
+    use crate::tools::schema::RiskLevel;
+    use serde_json::json;
+
+    fn tool(id: &str, is_kernel: bool) -> ToolDefinition {
+        let result = json!({ "tool": id });
+        ToolDefinition {
+            tool_id: id.to_string(),
+            input_schema: json!({}),
+            output_schema: json!({}),
+            risk_level: RiskLevel::Safe,
+            is_kernel,
+            handler: Box::new(move |_args, _ctx| Ok(result.clone())),
+        }
+    }
+
+    fn runtime() -> ToolRuntime {
+        let mut registry = ToolRegistry::new();
+        registry.register_kernel_tool(tool("notes.latest", true));
+        registry.register_instance_tool("calendar".to_string(), tool("calendar.create_event", false));
+        registry.register_instance_tool("slack".to_string(), tool("slack.post", false));
+        ToolRuntime {
+            registry: Arc::new(Mutex::new(registry)),
+            workspace: Arc::new(Mutex::new(Workspace::new("test".to_string()))),
+            storage: Arc::new(Mutex::new(Box::new(MemoryStorage::new()) as Box<dyn Storage>)),
+            permission_store: Arc::new(Mutex::new(PermissionStore::new())),
+            breaker: Arc::new(Mutex::new(CircuitBreaker::default())),
+            poisoned_locks: Arc::new(Mutex::new(Vec::new())),
+            instance_id: "kernel".to_string(),
+        }
+    }
+
+    fn names(descriptors: &[ToolDescriptor]) -> Vec<(&str, &str)> {
+        descriptors
+            .iter()
+            .map(|d| (d.name.as_str(), d.instance_id.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn routed_instance_tools_are_exposed_with_their_owner() {
+        let runtime = runtime();
+        let registry = runtime.registry.lock().unwrap();
+        let descriptors = tool_descriptors(&registry, "kernel", &["calendar".to_string()]);
+        assert_eq!(
+            names(&descriptors),
+            vec![
+                ("notes_latest", "kernel"),
+                ("calendar_create_event", "calendar"),
+            ]
+        );
+    }
+
+    #[test]
+    fn no_routed_instances_exposes_only_kernel_tools() {
+        let runtime = runtime();
+        let registry = runtime.registry.lock().unwrap();
+        let descriptors = tool_descriptors(&registry, "kernel", &[]);
+        assert_eq!(names(&descriptors), vec![("notes_latest", "kernel")]);
+
+        let repeated = ["slack".to_string(), "slack".to_string()];
+        let descriptors = tool_descriptors(&registry, "kernel", &repeated);
+        assert_eq!(descriptors.len(), 2);
+    }
+
+    #[test]
+    fn instance_tool_dispatches_under_owning_instance() {
+        let runtime = runtime();
+        let result = run_tool(&runtime, "calendar", "calendar.create_event", json!({}), Uuid::new_v4());
+        assert_eq!(result.unwrap()["tool"], "calendar.create_event");
+
+        // The runtime's own instance cannot see another app's tools.
+        let error = run_tool(&runtime, "kernel", "calendar.create_event", json!({}), Uuid::new_v4())
+            .unwrap_err();
+        assert!(error["reason"].as_str().unwrap().contains("unknown tool"));
+    }
+
+    #[test]
+    fn poisoned_lock_is_recovered_and_logged() {
+        let runtime = Arc::new(runtime());
+        let poisoner = Arc::clone(&runtime);
+        let result = std::thread::spawn(move || {
+            let _guard = poisoner.workspace.lock().unwrap();
+            panic!("panic while holding workspace lock");
+        })
+        .join();
+        assert!(result.is_err());
+
+        let result = run_tool(&runtime, "kernel", "notes.latest", json!({}), Uuid::new_v4());
+        assert_eq!(result.unwrap()["tool"], "notes.latest");
+        assert!(!runtime.workspace.is_poisoned());
+
+        let storage = runtime.storage.lock().unwrap();
+        let recovered = storage
+            .event_log()
+            .tail(100)
+            .into_iter()
+            .filter(|record| {
+                matches!(&record.event, Event::ErrorRaised { code, .. } if code == "lock_poisoned")
+            })
+            .count();
+        assert_eq!(recovered, 1);
+    }
+}
@@ -6,5 +6,5 @@ pub mod store;
 pub mod enforcement;
 
 pub use scopes::PermissionScope;
-pub use store::{PermissionDecision, PermissionStore};
-pub use enforcement::{EnforcementResult, enforce_permissions};
+pub use store::{PermissionDecision, PermissionGrant, PermissionStore};
+pub use enforcement::{EnforcementResult, enforce_permissions, enforce_permissions_with_limit};
@@ -2,7 +2,7 @@
 
 use uuid::Uuid;
 
-use crate::tools::ToolDefinition;
+use crate::tools::{RiskLevel, ToolDefinition};
 use super::risk::risk_for_tool;
 use super::store::{PermissionDecision, PermissionStore};
 
@@ -34,12 +34,29 @@ pub fn enforce_permissions(
     tool: &ToolDefinition,
     store: &PermissionStore,
     tool_call_id: Uuid,
+) -> EnforcementResult {
+    enforce_permissions_with_limit(tool, store, &RiskLevel::Destructive, tool_call_id)
+}
+
+/// Enforce permissions, additionally requiring confirmation for any tool whose
+/// risk level exceeds `max_auto_risk`, even if a stored decision allows it.
+///
+/// A stored `Deny` still wins over the limit.
+pub fn enforce_permissions_with_limit(
+    tool: &ToolDefinition,
+    store: &PermissionStore,
+    max_auto_risk: &RiskLevel,
+    tool_call_id: Uuid,
 ) -> EnforcementResult {
     let scope = risk_for_tool(&tool.risk_level);
+    let above_limit = tool.risk_level > *max_auto_risk;
 
     // Check stored decision
     if let Some(decision) = store.get_decision(&tool.tool_id, &scope) {
         return match decision {
+            PermissionDecision::Allow if above_limit => EnforcementResult::NeedsConfirmation {
+                confirmation_id: make_confirmation_id(tool_call_id, &tool.tool_id),
+            },
             PermissionDecision::Allow => EnforcementResult::Allowed,
             PermissionDecision::Deny => EnforcementResult::Denied {
                 reason: format!("tool '{}' denied by stored permission", tool.tool_id),
@@ -52,8 +69,8 @@ pub fn enforce_permissions(
 
     // No stored decision: derive from risk level
     match tool.risk_level {
-        crate::tools::RiskLevel::Safe => EnforcementResult::Allowed,
-        crate::tools::RiskLevel::Confirm | crate::tools::RiskLevel::Destructive => {
+        RiskLevel::Safe if !above_limit => EnforcementResult::Allowed,
+        RiskLevel::Safe | RiskLevel::Confirm | RiskLevel::Destructive => {
             EnforcementResult::NeedsConfirmation {
                 confirmation_id: make_confirmation_id(tool_call_id, &tool.tool_id),
             }
@@ -174,4 +191,45 @@ mod tests {
         let r2 = enforce_permissions(&tool, &store, uuid);
         assert_eq!(r1, r2);
     }
+
+    #[test]
+    fn stored_allow_above_limit_needs_confirmation() {
+        let tool = make_tool("delete_all", RiskLevel::Destructive);
+        let mut store = PermissionStore::new();
+        store.set_decision(
+            "delete_all".to_string(),
+            super::super::scopes::PermissionScope::Execute,
+            PermissionDecision::Allow,
+        );
+        let result =
+            enforce_permissions_with_limit(&tool, &store, &RiskLevel::Confirm, Uuid::new_v4());
+        assert!(matches!(result, EnforcementResult::NeedsConfirmation { .. }));
+
+        let result =
+            enforce_permissions_with_limit(&tool, &store, &RiskLevel::Destructive, Uuid::new_v4());
+        assert_eq!(result, EnforcementResult::Allowed);
+    }
+
+    #[test]
+    fn stored_deny_wins_over_limit() {
+        let tool = make_tool("write_file", RiskLevel::Confirm);
+        let mut store = PermissionStore::new();
+        store.set_decision(
+            "write_file".to_string(),
+            super::super::scopes::PermissionScope::Write,
+            PermissionDecision::Deny,
+        );
+        let result =
+            enforce_permissions_with_limit(&tool, &store, &RiskLevel::Safe, Uuid::new_v4());
+        assert!(matches!(result, EnforcementResult::Denied { .. }));
+    }
+
+    #[test]
+    fn safe_tool_within_limit_allowed() {
+        let tool = make_tool("read_file", RiskLevel::Safe);
+        let store = PermissionStore::new();
+        let result =
+            enforce_permissions_with_limit(&tool, &store, &RiskLevel::Safe, Uuid::new_v4());
+        assert_eq!(result, EnforcementResult::Allowed);
+    }
 }
@@ -3,7 +3,7 @@
 use serde::{Deserialize, Serialize};
 
 /// The scope of permission required for a tool invocation.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum PermissionScope {
     /// Non-destructive data access.
     Read,
@@ -1,6 +1,12 @@
-//! In-memory permission decision store (session-scoped).
+//! In-memory permission decision store.
+//!
+//! Decisions are session-scoped unless granted with `remember`, in which case
+//! they are reported by [`PermissionStore::remembered_grants`] for persistence.
+
+use std::collections::{BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
 
-use std::collections::HashMap;
 use super::scopes::PermissionScope;
 
 /// A permission decision for a (tool, scope) pair.
@@ -14,12 +20,21 @@ pub enum PermissionDecision {
     Deny,
 }
 
-/// Session-scoped store for permission decisions.
+/// An "always allow" grant that should survive restarts.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct PermissionGrant {
+    pub tool_id: String,
+    pub scope: PermissionScope,
+}
+
+/// Store for permission decisions.
 ///
-/// Decisions are keyed by `(tool_id, scope)` and live only for the
-/// duration of the session (no persistence).
+/// Decisions are keyed by `(tool_id, scope)` and live for the duration of
+/// the session, except grants made with `remember`, which the owner is
+/// expected to persist and restore via [`PermissionStore::restore_grants`].
 pub struct PermissionStore {
     decisions: HashMap<(String, PermissionScope), PermissionDecision>,
+    remembered: BTreeSet<PermissionGrant>,
 }
 
 impl PermissionStore {
@@ -27,22 +42,54 @@ impl PermissionStore {
     pub fn new() -> Self {
         Self {
             decisions: HashMap::new(),
+            remembered: BTreeSet::new(),
         }
     }
 
-    /// Store a permission decision for a (tool_id, scope) pair.
+    /// Store a session-only permission decision for a (tool_id, scope) pair.
+    ///
+    /// Replaces any remembered grant for the pair.
     pub fn set_decision(&mut self, tool_id: String, scope: PermissionScope, decision: PermissionDecision) {
+        self.remembered.remove(&PermissionGrant {
+            tool_id: tool_id.clone(),
+            scope: scope.clone(),
+        });
         self.decisions.insert((tool_id, scope), decision);
     }
 
+    /// Allow a (tool_id, scope) pair. With `remember`, the grant is reported
+    /// by [`PermissionStore::remembered_grants`]; otherwise it is session-only.
+    pub fn grant(&mut self, tool_id: String, scope: PermissionScope, remember: bool) {
+        self.set_decision(tool_id.clone(), scope.clone(), PermissionDecision::Allow);
+        if remember {
+            self.remembered.insert(PermissionGrant { tool_id, scope });
+        }
+    }
+
+    /// Grants that should be persisted, in a stable order.
+    pub fn remembered_grants(&self) -> Vec<PermissionGrant> {
+        self.remembered.iter().cloned().collect()
+    }
+
+    /// Re-apply grants loaded from persistence as remembered grants.
+    pub fn restore_grants(&mut self, grants: Vec<PermissionGrant>) {
+        for grant in grants {
+            self.grant(grant.tool_id, grant.scope, true);
+        }
+    }
+
     /// Retrieve the stored decision for a (tool_id, scope) pair.
     pub fn get_decision(&self, tool_id: &str, scope: &PermissionScope) -> Option<&PermissionDecision> {
         self.decisions.get(&(tool_id.to_string(), scope.clone()))
     }
 
-    /// Remove a stored decision for a (tool_id, scope) pair.
+    /// Remove any decision for a (tool_id, scope) pair, remembered or not.
     pub fn clear_decision(&mut self, tool_id: &str, scope: &PermissionScope) {
         self.decisions.remove(&(tool_id.to_string(), scope.clone()));
+        self.remembered.remove(&PermissionGrant {
+            tool_id: tool_id.to_string(),
+            scope: scope.clone(),
+        });
     }
 }
 
@@ -109,4 +156,51 @@ mod tests {
             Some(&PermissionDecision::Deny)
         );
     }
+
+    #[test]
+    fn remembered_grant_is_reported() {
+        let mut store = PermissionStore::new();
+        store.grant("tool_a".to_string(), PermissionScope::Write, true);
+        store.grant("tool_b".to_string(), PermissionScope::Write, false);
+
+        assert_eq!(
+            store.get_decision("tool_b", &PermissionScope::Write),
+            Some(&PermissionDecision::Allow)
+        );
+        assert_eq!(
+            store.remembered_grants(),
+            vec![PermissionGrant {
+                tool_id: "tool_a".to_string(),
+                scope: PermissionScope::Write,
+            }]
+        );
+    }
+
+    #[test]
+    fn clear_and_overwrite_forget_grant() {
+        let mut store = PermissionStore::new();
+        store.grant("tool_a".to_string(), PermissionScope::Write, true);
+        store.grant("tool_b".to_string(), PermissionScope::Write, true);
+
+        store.clear_decision("tool_a", &PermissionScope::Write);
+        store.set_decision("tool_b".to_string(), PermissionScope::Write, PermissionDecision::Deny);
+
+        assert_eq!(store.get_decision("tool_a", &PermissionScope::Write), None);
+        assert!(store.remembered_grants().is_empty());
+    }
+
+    #[test]
+    fn restore_grants_allows_and_remembers() {
+        let mut store = PermissionStore::new();
+        store.restore_grants(vec![PermissionGrant {
+            tool_id: "tool_a".to_string(),
+            scope: PermissionScope::Execute,
+        }]);
+
+        assert_eq!(
+            store.get_decision("tool_a", &PermissionScope::Execute),
+            Some(&PermissionDecision::Allow)
+        );
+        assert_eq!(store.remembered_grants().len(), 1);
+    }
 }
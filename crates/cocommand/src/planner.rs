@@ -6,6 +6,6 @@ pub mod llm_planner;
 pub mod types;
 
 pub use plan::{Plan, PlannedToolCall};
-pub use planner::{Planner, StubPlanner};
+pub use planner::{Planner, StubPlanner, TextSink};
 pub use llm_planner::LlmPlanner;
 pub use types::{PlanMetadata, PlannerError, PlannerInput, PlannerOutput, ToolSpec};
@@ -1,9 +1,11 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use futures_util::StreamExt;
 use llm_kit_core::agent::{Agent, AgentCallParameters, AgentSettings};
 use llm_kit_core::generate_text::{step_count_is, StepResult, StopCondition};
 use llm_kit_core::output::Output;
+use llm_kit_core::tool::ToolSet;
 use llm_kit_provider::LanguageModel;
 use llm_kit_core::AgentInterface;
 use llm_kit_provider::error::ProviderError;
@@ -11,7 +13,12 @@ use serde_json::Value;
 use std::error::Error;
 
 use super::plan::{Plan, PlannedToolCall};
+use super::planner::TextSink;
 use super::types::{PlanMetadata, PlannerError, PlannerInput, PlannerOutput};
+use crate::workspace::Workspace;
+
+/// Appended to the response when the step cap cut a tool loop short.
+const MAX_ITERATIONS_NOTE: &str = "(reached max tool iterations)";
 
 /// LLM-backed planner that uses llm-kit to execute tool calls.
 pub struct LlmPlanner {
@@ -40,17 +47,24 @@ impl LlmPlanner {
     }
 }
 
-#[async_trait]
-impl super::Planner for LlmPlanner {
-    async fn plan(&self, input: PlannerInput) -> Result<PlannerOutput, PlannerError> {
-        let Some(toolset) = input.toolset else {
-            return Ok(PlannerOutput::new(Plan::empty(), PlanMetadata::stub(), None, vec![]));
-        };
-
-        println!("[planner] llm planner invoked");
-
+impl LlmPlanner {
+    /// Agent settings for one run, plus the step cap in effect.
+    ///
+    /// The workspace's `max_tool_iterations` overrides the planner default.
+    /// A cap of 0 is treated as 1, since the model must be called at least
+    /// once.
+    fn agent_settings(
+        &self,
+        toolset: ToolSet,
+        workspace: &Workspace,
+    ) -> (AgentSettings, u32) {
+        let max_steps = workspace
+            .config
+            .max_tool_iterations
+            .unwrap_or(self.max_steps)
+            .max(1);
         let stop_conditions: Vec<Arc<dyn StopCondition>> = vec![
-            Arc::new(step_count_is(self.max_steps as usize)),
+            Arc::new(step_count_is(max_steps as usize)),
             Arc::new(ApprovalRequiredStop),
         ];
 
@@ -58,10 +72,27 @@ impl super::Planner for LlmPlanner {
             .with_tools(toolset)
             .with_stop_when(stop_conditions);
 
-        if let Some(instructions) = &self.instructions {
-            settings = settings.with_instructions(instructions.clone());
+        let workspace_prompt = workspace.config.system_prompt.as_deref();
+        if let Some(instructions) =
+            compose_instructions(self.instructions.as_deref(), workspace_prompt)
+        {
+            settings = settings.with_instructions(instructions);
         }
 
+        (settings, max_steps)
+    }
+}
+
+#[async_trait]
+impl super::Planner for LlmPlanner {
+    async fn plan(&self, input: PlannerInput) -> Result<PlannerOutput, PlannerError> {
+        let Some(toolset) = input.toolset else {
+            return Ok(PlannerOutput::new(Plan::empty(), PlanMetadata::stub(), None, vec![]));
+        };
+
+        println!("[planner] llm planner invoked");
+
+        let (settings, max_steps) = self.agent_settings(toolset, &input.workspace);
         let agent = Agent::new(settings);
 
         let prompt = input.command.raw_text;
@@ -78,52 +109,174 @@ impl super::Planner for LlmPlanner {
                 PlannerError::ProviderUnavailable(e.to_string())
             })?;
 
-        let mut steps = Vec::new();
-        let mut tool_errors = Vec::new();
-        for step in &result.steps {
-            for tool_call in step.tool_calls() {
-                steps.push(PlannedToolCall {
-                    tool_id: tool_call.tool_name.clone(),
-                    args: tool_call.input.clone(),
-                });
-            }
-            for part in &step.content {
-                if let Output::ToolError(error) = part {
-                    tool_errors.push(error.error.clone());
-                }
-            }
-        }
+        let total_usage = result.total_usage;
+        Ok(build_output(
+            RunResult {
+                steps: &result.steps,
+                text: result.text,
+                model: result.response.model_id,
+                reasoning: result.reasoning_text,
+                prompt_tokens: total_usage.input_tokens,
+                completion_tokens: total_usage.output_tokens,
+                total_tokens: total_usage.total(),
+            },
+            max_steps,
+        ))
+    }
 
-        let plan = if steps.is_empty() {
-            Plan::empty()
-        } else {
-            Plan::new(steps)
+    /// Stream the run, forwarding each text delta to `on_text` as the model
+    /// produces it.
+    async fn plan_streaming(
+        &self,
+        input: PlannerInput,
+        on_text: TextSink,
+    ) -> Result<PlannerOutput, PlannerError> {
+        let Some(toolset) = input.toolset else {
+            return Ok(PlannerOutput::new(Plan::empty(), PlanMetadata::stub(), None, vec![]));
         };
 
-        let total_usage = result.total_usage;
-        let metadata = PlanMetadata {
-            planner_id: "llm".to_string(),
-            model: result.response.model_id,
-            reasoning: result.reasoning_text,
-            prompt_tokens: u64_to_u32(total_usage.input_tokens),
-            completion_tokens: u64_to_u32(total_usage.output_tokens),
-            total_tokens: u64_to_u32(total_usage.total()),
+        println!("[planner] llm planner invoked (streaming)");
+
+        let (settings, max_steps) = self.agent_settings(toolset, &input.workspace);
+        let agent = Agent::new(settings);
+
+        let prompt = input.command.raw_text;
+        let result = agent
+            .stream(AgentCallParameters::from_text(prompt))
+            .map_err(|e| {
+                log_llm_error("stream", &e);
+                PlannerError::Internal(e.to_string())
+            })?
+            .execute()
+            .await
+            .map_err(|e| {
+                log_llm_error("execute", &e);
+                PlannerError::ProviderUnavailable(e.to_string())
+            })?;
+
+        let mut text_stream = result.text_stream();
+        while let Some(delta) = text_stream.next().await {
+            if !delta.is_empty() {
+                on_text(&delta);
+            }
+        }
+
+        let unavailable = |e: &dyn Error| {
+            log_llm_error("stream", e);
+            PlannerError::ProviderUnavailable(e.to_string())
         };
+        let steps = result.steps().await.map_err(|e| unavailable(&e))?;
+        let text = result.text().await.map_err(|e| unavailable(&e))?;
+        let response = result.response().await.map_err(|e| unavailable(&e))?;
+        let reasoning = result.reasoning_text().await.map_err(|e| unavailable(&e))?;
+        let total_usage = result.total_usage().await.map_err(|e| unavailable(&e))?;
 
-        println!(
-            "[planner] llm response_text_len={} tool_errors={}",
-            result.text.len(),
-            tool_errors.len()
-        );
-        Ok(PlannerOutput::new(
-            plan,
-            metadata,
-            Some(result.text),
-            tool_errors,
+        Ok(build_output(
+            RunResult {
+                steps: &steps,
+                text,
+                model: response.model_id,
+                reasoning,
+                prompt_tokens: total_usage.input_tokens,
+                completion_tokens: total_usage.output_tokens,
+                total_tokens: total_usage.total(),
+            },
+            max_steps,
         ))
     }
 }
 
+/// The parts of a finished agent run the planner output is built from,
+/// shared by the blocking and streaming paths.
+struct RunResult<'a> {
+    steps: &'a [StepResult],
+    text: String,
+    model: Option<String>,
+    reasoning: Option<String>,
+    prompt_tokens: u64,
+    completion_tokens: u64,
+    total_tokens: u64,
+}
+
+fn build_output(run: RunResult<'_>, max_steps: u32) -> PlannerOutput {
+    let mut steps = Vec::new();
+    let mut tool_errors = Vec::new();
+    for step in run.steps {
+        for tool_call in step.tool_calls() {
+            steps.push(PlannedToolCall {
+                tool_id: tool_call.tool_name.clone(),
+                args: tool_call.input.clone(),
+            });
+        }
+        for part in &step.content {
+            if let Output::ToolError(error) = part {
+                tool_errors.push(error.error.clone());
+            }
+        }
+    }
+
+    let last_step_called_tools = run
+        .steps
+        .last()
+        .is_some_and(|step| step.tool_calls().into_iter().next().is_some());
+    let hit_cap = run.steps.len() >= max_steps as usize && last_step_called_tools;
+
+    let plan = if steps.is_empty() {
+        Plan::empty()
+    } else {
+        Plan::new(steps)
+    };
+
+    let metadata = PlanMetadata {
+        planner_id: "llm".to_string(),
+        model: run.model,
+        reasoning: run.reasoning,
+        prompt_tokens: u64_to_u32(run.prompt_tokens),
+        completion_tokens: u64_to_u32(run.completion_tokens),
+        total_tokens: u64_to_u32(run.total_tokens),
+    };
+
+    println!(
+        "[planner] llm response_text_len={} tool_errors={}",
+        run.text.len(),
+        tool_errors.len()
+    );
+    PlannerOutput::new(
+        plan,
+        metadata,
+        Some(finalize_text(run.text, hit_cap)),
+        tool_errors,
+    )
+}
+
+/// Layer the workspace system prompt on top of the planner's own
+/// instructions. Blank prompts are ignored.
+fn compose_instructions(base: Option<&str>, workspace_prompt: Option<&str>) -> Option<String> {
+    let parts: Vec<&str> = [base, workspace_prompt]
+        .into_iter()
+        .flatten()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect();
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join("\n\n"))
+    }
+}
+
+/// Note a truncated tool loop in the response so the user knows the command
+/// did not run to completion.
+fn finalize_text(text: String, hit_cap: bool) -> String {
+    if !hit_cap {
+        text
+    } else if text.trim().is_empty() {
+        MAX_ITERATIONS_NOTE.to_string()
+    } else {
+        format!("{}\n\n{MAX_ITERATIONS_NOTE}", text.trim_end())
+    }
+}
+
 fn u64_to_u32(value: u64) -> Option<u32> {
     Some(u32::try_from(value).unwrap_or(u32::MAX))
 }
@@ -176,3 +329,157 @@ fn log_llm_error(stage: &str, err: &(dyn Error + 'static)) {
         depth += 1;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use llm_kit_provider::language_model::call_options::LanguageModelCallOptions;
+    use llm_kit_provider::language_model::content::tool_call::LanguageModelToolCall;
+    use llm_kit_provider::language_model::content::LanguageModelContent;
+    use llm_kit_provider::language_model::finish_reason::LanguageModelFinishReason;
+    use llm_kit_provider::language_model::usage::LanguageModelUsage;
+    use llm_kit_provider::language_model::{
+        LanguageModelGenerateResponse, LanguageModelStreamResponse,
+    };
+
+    use crate::command::ParsedCommand;
+    use crate::planner::Planner;
+    use crate::storage::MemoryStorage;
+    use crate::tools::{RiskLevel, ToolDefinition};
+    use crate::Core;
+
+    /// Model that answers every call with a `notes_latest` tool call, so the
+    /// agent loop only ends at the step cap.
+    #[derive(Default)]
+    struct AlwaysCallsTool {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl LanguageModel for AlwaysCallsTool {
+        fn provider(&self) -> &str {
+            "stub"
+        }
+
+        fn model_id(&self) -> &str {
+            "always-calls-tool"
+        }
+
+        async fn do_generate(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelGenerateResponse, Box<dyn Error + Send + Sync>> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(LanguageModelGenerateResponse {
+                content: vec![LanguageModelContent::ToolCall(LanguageModelToolCall::new(
+                    format!("call-{call}"),
+                    "notes_latest",
+                    "{}",
+                ))],
+                finish_reason: LanguageModelFinishReason::ToolCalls,
+                usage: LanguageModelUsage::default(),
+                provider_metadata: None,
+                request: None,
+                response: None,
+                warnings: vec![],
+            })
+        }
+
+        async fn do_stream(
+            &self,
+            _options: LanguageModelCallOptions,
+        ) -> Result<LanguageModelStreamResponse, Box<dyn Error + Send + Sync>> {
+            Err("streaming is not used by this stub".into())
+        }
+    }
+
+    fn capped_input(max_tool_iterations: Option<u32>) -> PlannerInput {
+        let mut core = Core::new(Box::new(MemoryStorage::new()));
+        core.registry_mut().register_kernel_tool(ToolDefinition {
+            tool_id: "notes.latest".to_string(),
+            input_schema: serde_json::json!({}),
+            output_schema: serde_json::json!({}),
+            risk_level: RiskLevel::Safe,
+            is_kernel: true,
+            handler: Box::new(|_args, _ctx| Ok(serde_json::json!({ "note": "hi" }))),
+        });
+        core.set_max_tool_iterations(max_tool_iterations).unwrap();
+
+        PlannerInput {
+            command: ParsedCommand {
+                raw_text: "show my notes".to_string(),
+                normalized_text: "show my notes".to_string(),
+                tags: vec![],
+                tokens: vec![],
+                flags: Default::default(),
+            },
+            candidates: vec![],
+            workspace: core.workspace(),
+            tools: vec![],
+            toolset: Some(core.build_llm_toolset("kernel")),
+        }
+    }
+
+    #[tokio::test]
+    async fn endless_tool_loop_stops_at_step_cap() {
+        let model = Arc::new(AlwaysCallsTool::default());
+        let planner = LlmPlanner::new(model.clone()).with_max_steps(3);
+
+        let output = planner.plan(capped_input(None)).await.unwrap();
+
+        assert_eq!(model.calls.load(Ordering::SeqCst), 3);
+        assert_eq!(output.plan.steps.len(), 3);
+        assert!(output
+            .response_text
+            .unwrap()
+            .ends_with(MAX_ITERATIONS_NOTE));
+    }
+
+    #[tokio::test]
+    async fn workspace_cap_overrides_planner_default() {
+        let model = Arc::new(AlwaysCallsTool::default());
+        let planner = LlmPlanner::new(model.clone()).with_max_steps(6);
+
+        let output = planner.plan(capped_input(Some(2))).await.unwrap();
+
+        assert_eq!(model.calls.load(Ordering::SeqCst), 2);
+        assert_eq!(output.response_text.as_deref(), Some(MAX_ITERATIONS_NOTE));
+    }
+
+    #[test]
+    fn workspace_prompt_is_layered_after_planner_instructions() {
+        let composed = compose_instructions(Some("Be terse."), Some("You are a calendar assistant."));
+        assert_eq!(
+            composed.as_deref(),
+            Some("Be terse.\n\nYou are a calendar assistant.")
+        );
+    }
+
+    #[test]
+    fn workspace_prompt_alone_is_used() {
+        let composed = compose_instructions(None, Some("Never delete notes."));
+        assert_eq!(composed.as_deref(), Some("Never delete notes."));
+    }
+
+    #[test]
+    fn empty_config_injects_nothing() {
+        assert_eq!(compose_instructions(None, None), None);
+        assert_eq!(compose_instructions(None, Some("   ")), None);
+    }
+
+    #[test]
+    fn capped_run_is_noted_in_response() {
+        assert_eq!(
+            finalize_text("Opened Safari.".to_string(), true),
+            "Opened Safari.\n\n(reached max tool iterations)"
+        );
+        assert_eq!(finalize_text(String::new(), true), MAX_ITERATIONS_NOTE);
+    }
+
+    #[test]
+    fn uncapped_run_text_is_unchanged() {
+        assert_eq!(finalize_text("Done.".to_string(), false), "Done.");
+    }
+}
@@ -1,12 +1,37 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 
 use super::plan::{Plan, PlannedToolCall};
 use super::types::{PlanMetadata, PlannerError, PlannerInput, PlannerOutput};
 
+/// Callback receiving response text incrementally while a planner runs.
+pub type TextSink = Arc<dyn Fn(&str) + Send + Sync>;
+
 /// Trait for planning tool call sequences from a command and routing candidates.
 #[async_trait]
 pub trait Planner: Send + Sync {
     async fn plan(&self, input: PlannerInput) -> Result<PlannerOutput, PlannerError>;
+
+    /// Plan while forwarding response text to `on_text` as it is produced.
+    ///
+    /// The default delegates to [`Planner::plan`] and emits the final
+    /// response text as a single chunk.
+    async fn plan_streaming(
+        &self,
+        input: PlannerInput,
+        on_text: TextSink,
+    ) -> Result<PlannerOutput, PlannerError> {
+        let output = self.plan(input).await?;
+        if let Some(text) = output
+            .response_text
+            .as_deref()
+            .filter(|t| !t.trim().is_empty())
+        {
+            on_text(text);
+        }
+        Ok(output)
+    }
 }
 
 /// Deterministic stub planner for v0.
@@ -46,6 +71,8 @@ mod tests {
             raw_text: text.to_string(),
             normalized_text: text.to_string(),
             tags: vec![],
+            tokens: vec![],
+            flags: Default::default(),
         }
     }
 
@@ -108,6 +135,36 @@ mod tests {
         assert_eq!(plan1, plan2);
     }
 
+    struct TextPlanner;
+
+    #[async_trait]
+    impl Planner for TextPlanner {
+        async fn plan(&self, _input: PlannerInput) -> Result<PlannerOutput, PlannerError> {
+            Ok(PlannerOutput::new(
+                Plan::empty(),
+                PlanMetadata::stub(),
+                Some("all done".to_string()),
+                vec![],
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn default_streaming_emits_response_text_once() {
+        let chunks = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink_chunks = Arc::clone(&chunks);
+        let sink: TextSink = Arc::new(move |chunk| sink_chunks.lock().unwrap().push(chunk.to_string()));
+
+        TextPlanner
+            .plan_streaming(make_input(vec![]), Arc::clone(&sink))
+            .await
+            .unwrap();
+        // Planners without response text emit nothing.
+        StubPlanner.plan_streaming(make_input(vec![]), sink).await.unwrap();
+
+        assert_eq!(*chunks.lock().unwrap(), vec!["all done".to_string()]);
+    }
+
     #[test]
     fn plan_preserves_step_ordering() {
         let steps = vec![
@@ -12,6 +12,8 @@ pub struct ToolSpec {
     pub output_schema: serde_json::Value,
     pub risk_level: RiskLevel,
     pub is_kernel: bool,
+    /// Instance (app/extension) that owns the tool; `None` for kernel tools.
+    pub instance_id: Option<String>,
 }
 
 /// Structured input to a planner.
@@ -3,8 +3,14 @@
 //! Defines traits for OS-level capabilities so the core crate never depends
 //! on platform-specific APIs directly.
 
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+/// Error message returned by providers on platforms without support.
+pub const UNSUPPORTED_PLATFORM: &str = "not supported on this platform";
+
 /// Abstraction over clipboard access.
 ///
 /// Implementations provide clipboard history to built-in tools without
@@ -15,6 +21,16 @@ pub trait ClipboardProvider: Send + Sync {
 
     /// Returns the most recent clipboard entry, if any.
     fn get_latest(&self) -> Option<Value>;
+
+    /// Replaces the current clipboard contents with the given text.
+    fn set_text(&self, text: &str) -> Result<(), String>;
+
+    /// A counter that changes whenever the clipboard contents change, such
+    /// as the macOS pasteboard `changeCount`. `None` if the platform has no
+    /// such counter, in which case recorders compare contents instead.
+    fn change_count(&self) -> Option<u64> {
+        None
+    }
 }
 
 /// A no-op provider that always returns empty results.
@@ -30,26 +46,177 @@ impl ClipboardProvider for NullClipboardProvider {
     fn get_latest(&self) -> Option<Value> {
         None
     }
+
+    fn set_text(&self, _text: &str) -> Result<(), String> {
+        Err("clipboard unavailable".to_string())
+    }
 }
 
 /// A test-only provider pre-loaded with entries.
+///
+/// `set_text` appends a `{"text": ...}` entry so restores are observable.
 pub struct MockClipboardProvider {
-    entries: Vec<Value>,
+    entries: Mutex<Vec<Value>>,
 }
 
 impl MockClipboardProvider {
     pub fn new(entries: Vec<Value>) -> Self {
-        Self { entries }
+        Self {
+            entries: Mutex::new(entries),
+        }
     }
 }
 
 impl ClipboardProvider for MockClipboardProvider {
     fn get_history(&self) -> Vec<Value> {
-        self.entries.clone()
+        self.entries.lock().expect("clipboard lock").clone()
     }
 
     fn get_latest(&self) -> Option<Value> {
-        self.entries.last().cloned()
+        self.entries.lock().expect("clipboard lock").last().cloned()
+    }
+
+    fn set_text(&self, text: &str) -> Result<(), String> {
+        self.entries
+            .lock()
+            .expect("clipboard lock")
+            .push(serde_json::json!({ "text": text }));
+        Ok(())
+    }
+}
+
+/// An application installed on the system.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstalledApp {
+    pub name: String,
+    pub bundle_id: Option<String>,
+    pub path: String,
+}
+
+/// An application that is currently running.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunningApp {
+    pub name: String,
+    pub bundle_id: Option<String>,
+    pub pid: i32,
+    pub is_active: bool,
+}
+
+/// An action that can be performed on a running application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AppAction {
+    /// Bring the app to the foreground.
+    Activate,
+    /// Hide all of the app's windows.
+    Hide,
+    /// Ask the app to quit.
+    Quit,
+}
+
+/// Abstraction over installed/running application management.
+pub trait AppsProvider: Send + Sync {
+    /// Returns applications installed on the system.
+    fn list_installed_apps(&self) -> Result<Vec<InstalledApp>, String>;
+
+    /// Returns applications that are currently running.
+    fn list_running_apps(&self) -> Result<Vec<RunningApp>, String>;
+
+    /// Launches (or focuses) the application with the given bundle ID.
+    fn open_app(&self, bundle_id: &str) -> Result<(), String>;
+
+    /// Opens `path` with the application identified by `bundle_id`.
+    fn open_path_with(&self, path: &str, bundle_id: &str) -> Result<(), String>;
+
+    /// Performs an action on the running application with the given bundle ID.
+    fn perform_app_action(&self, bundle_id: &str, action: AppAction) -> Result<(), String>;
+}
+
+/// A provider for platforms without app management support.
+///
+/// Every call fails with [`UNSUPPORTED_PLATFORM`].
+pub struct NullAppsProvider;
+
+impl AppsProvider for NullAppsProvider {
+    fn list_installed_apps(&self) -> Result<Vec<InstalledApp>, String> {
+        Err(UNSUPPORTED_PLATFORM.to_string())
+    }
+
+    fn list_running_apps(&self) -> Result<Vec<RunningApp>, String> {
+        Err(UNSUPPORTED_PLATFORM.to_string())
+    }
+
+    fn open_app(&self, _bundle_id: &str) -> Result<(), String> {
+        Err(UNSUPPORTED_PLATFORM.to_string())
+    }
+
+    fn open_path_with(&self, _path: &str, _bundle_id: &str) -> Result<(), String> {
+        Err(UNSUPPORTED_PLATFORM.to_string())
+    }
+
+    fn perform_app_action(&self, _bundle_id: &str, _action: AppAction) -> Result<(), String> {
+        Err(UNSUPPORTED_PLATFORM.to_string())
+    }
+}
+
+/// A test-only provider with fixed app lists that records requested operations.
+pub struct MockAppsProvider {
+    installed: Vec<InstalledApp>,
+    running: Vec<RunningApp>,
+    calls: Mutex<Vec<String>>,
+}
+
+impl MockAppsProvider {
+    pub fn new(installed: Vec<InstalledApp>, running: Vec<RunningApp>) -> Self {
+        Self {
+            installed,
+            running,
+            calls: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Operations performed so far, formatted as `"open:<bundle_id>"`,
+    /// `"open_with:<bundle_id>:<path>"` or `"<action>:<bundle_id>"`.
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.lock().expect("apps lock").clone()
+    }
+}
+
+impl AppsProvider for MockAppsProvider {
+    fn list_installed_apps(&self) -> Result<Vec<InstalledApp>, String> {
+        Ok(self.installed.clone())
+    }
+
+    fn list_running_apps(&self) -> Result<Vec<RunningApp>, String> {
+        Ok(self.running.clone())
+    }
+
+    fn open_app(&self, bundle_id: &str) -> Result<(), String> {
+        self.calls
+            .lock()
+            .expect("apps lock")
+            .push(format!("open:{bundle_id}"));
+        Ok(())
+    }
+
+    fn open_path_with(&self, path: &str, bundle_id: &str) -> Result<(), String> {
+        self.calls
+            .lock()
+            .expect("apps lock")
+            .push(format!("open_with:{bundle_id}:{path}"));
+        Ok(())
+    }
+
+    fn perform_app_action(&self, bundle_id: &str, action: AppAction) -> Result<(), String> {
+        let action = serde_json::to_value(action)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default();
+        self.calls
+            .lock()
+            .expect("apps lock")
+            .push(format!("{action}:{bundle_id}"));
+        Ok(())
     }
 }
 
@@ -63,6 +230,7 @@ mod tests {
         let provider = NullClipboardProvider;
         assert!(provider.get_history().is_empty());
         assert!(provider.get_latest().is_none());
+        assert!(provider.set_text("x").is_err());
     }
 
     #[test]
@@ -74,4 +242,27 @@ mod tests {
         assert_eq!(provider.get_history().len(), 2);
         assert_eq!(provider.get_latest().unwrap()["text"], "second");
     }
+
+    #[test]
+    fn mock_provider_set_text_becomes_latest() {
+        let provider = MockClipboardProvider::new(vec![json!({"text": "old"})]);
+        provider.set_text("restored").unwrap();
+        assert_eq!(provider.get_latest().unwrap()["text"], "restored");
+    }
+
+    #[test]
+    fn null_apps_provider_is_unsupported() {
+        let provider = NullAppsProvider;
+        assert_eq!(provider.list_installed_apps().unwrap_err(), UNSUPPORTED_PLATFORM);
+        assert_eq!(provider.list_running_apps().unwrap_err(), UNSUPPORTED_PLATFORM);
+        assert!(provider.open_app("com.example").is_err());
+        assert!(provider.perform_app_action("com.example", AppAction::Quit).is_err());
+    }
+
+    #[test]
+    fn app_action_serde_is_lowercase() {
+        assert_eq!(serde_json::to_value(AppAction::Hide).unwrap(), json!("hide"));
+        let action: AppAction = serde_json::from_value(json!("quit")).unwrap();
+        assert_eq!(action, AppAction::Quit);
+    }
 }
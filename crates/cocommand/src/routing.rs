@@ -3,5 +3,5 @@
 pub mod metadata;
 pub mod router;
 
-pub use metadata::RoutingMetadata;
+pub use metadata::{RoutingMetadata, RoutingWeights};
 pub use router::{RouteCandidate, Router, RoutingResult};
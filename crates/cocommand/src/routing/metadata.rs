@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 /// Routing metadata for an application.
 ///
 /// Apps register this metadata with the router so commands can be
@@ -13,4 +15,31 @@ pub struct RoutingMetadata {
     pub verbs: Vec<String>,
     /// Objects/nouns this app operates on (e.g., ["clipboard", "text", "selection"]).
     pub objects: Vec<String>,
+    /// Per-signal score weights; the default reproduces the standard scheme.
+    pub weights: RoutingWeights,
+}
+
+/// Score added per matched signal when routing to an app.
+///
+/// Lets an app emphasize the signals that are most precise for it, e.g. a
+/// launcher boosting exact keyword hits. Unspecified fields in a manifest
+/// fall back to the defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RoutingWeights {
+    pub keyword: f64,
+    pub verb: f64,
+    pub object: f64,
+    pub example: f64,
+}
+
+impl Default for RoutingWeights {
+    fn default() -> Self {
+        Self {
+            keyword: 3.0,
+            verb: 2.0,
+            object: 2.0,
+            example: 4.0,
+        }
+    }
 }
@@ -1,10 +1,50 @@
 use crate::command::ParsedCommand;
 use crate::routing::RoutingMetadata;
+use crate::utils::fuzzy::fuzzy_match;
 use crate::workspace::state::FollowUpContext;
 
 /// Score bonus applied to the last-used app during follow-up mode.
 const FOLLOW_UP_BIAS: f64 = 5.0;
 
+/// Shortest token considered for a fuzzy keyword match.
+const FUZZY_MIN_TOKEN_LEN: usize = 4;
+
+/// Lowest similarity (see [`fuzzy_similarity`]) that counts as a match.
+const FUZZY_MIN_SIMILARITY: f64 = 0.6;
+
+/// Fuzzy score deducted for skipping one token char, so a swapped or wrong
+/// letter costs about as much as a missing one.
+const FUZZY_SKIPPED_CHAR_PENALTY: i64 = 16;
+
+/// How closely `token` matches `keyword`, as its fuzzy score relative to the
+/// keyword matching itself.
+///
+/// One token char may be skipped, which covers a swapped (`"clipbaord"`),
+/// wrong (`"calender"`) or extra letter. Returns `None` for short tokens and
+/// matches below [`FUZZY_MIN_SIMILARITY`].
+fn fuzzy_similarity(token: &str, keyword: &str) -> Option<f64> {
+    let chars: Vec<char> = token.chars().collect();
+    if chars.len() < FUZZY_MIN_TOKEN_LEN {
+        return None;
+    }
+    let exact = fuzzy_match(keyword, keyword)?.score;
+
+    let whole = fuzzy_match(token, keyword).map(|m| m.score);
+    let skipped = (0..chars.len()).filter_map(|skip| {
+        let rest: String = chars
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != skip)
+            .map(|(_, c)| c)
+            .collect();
+        fuzzy_match(&rest, keyword).map(|m| m.score - FUZZY_SKIPPED_CHAR_PENALTY)
+    });
+    let best = whole.into_iter().chain(skipped).max()?;
+
+    let similarity = best as f64 / exact as f64;
+    (similarity >= FUZZY_MIN_SIMILARITY).then_some(similarity)
+}
+
 /// A single routing candidate with score and explanation.
 #[derive(Debug, Clone)]
 pub struct RouteCandidate {
@@ -43,6 +83,9 @@ impl Router {
     }
 
     /// Register an app's routing metadata. All string fields are lowercased at registration.
+    ///
+    /// Registering an `app_id` that is already present replaces its metadata
+    /// in place, so each app is scored at most once.
     pub fn register(&mut self, metadata: RoutingMetadata) {
         let normalized = RoutingMetadata {
             app_id: metadata.app_id,
@@ -50,14 +93,27 @@ impl Router {
             examples: metadata.examples.into_iter().map(|s| s.to_lowercase()).collect(),
             verbs: metadata.verbs.into_iter().map(|s| s.to_lowercase()).collect(),
             objects: metadata.objects.into_iter().map(|s| s.to_lowercase()).collect(),
+            weights: metadata.weights,
         };
-        self.entries.push(normalized);
+        match self.entries.iter_mut().find(|e| e.app_id == normalized.app_id) {
+            Some(existing) => *existing = normalized,
+            None => self.entries.push(normalized),
+        }
+    }
+
+    /// Remove an app's routing metadata. Returns `false` if it was not registered.
+    pub fn unregister(&mut self, app_id: &str) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|e| e.app_id != app_id);
+        self.entries.len() != before
     }
 
     /// Route a parsed command to candidate apps.
     ///
-    /// Scoring:
+    /// Scoring uses each app's [`RoutingWeights`](crate::routing::RoutingWeights);
+    /// by default:
     /// - Keyword match: +3 per match
+    /// - Fuzzy keyword match (typo): keyword weight scaled by similarity
     /// - Verb match: +2 per match
     /// - Object match: +2 per match
     /// - Example substring match: +4 per matching example
@@ -65,11 +121,20 @@ impl Router {
     /// If `ParsedCommand.tags` is non-empty, only apps whose `app_id` is in the
     /// tag set are considered (hard allowlist).
     pub fn route(&self, command: &ParsedCommand) -> RoutingResult {
+        // Quoted multi-word phrases also count as tokens, so an object like
+        // "shopping list" can match as a whole.
         let tokens: Vec<String> = command
             .normalized_text
             .to_lowercase()
             .split_whitespace()
             .map(|s| s.to_string())
+            .chain(
+                command
+                    .tokens
+                    .iter()
+                    .filter(|t| t.contains(' '))
+                    .map(|t| t.to_lowercase()),
+            )
             .collect();
 
         let input_lower = command.normalized_text.to_lowercase();
@@ -90,53 +155,76 @@ impl Router {
                 let mut score: f64 = 0.0;
                 let mut explanations: Vec<String> = Vec::new();
 
-                // Keyword matches (+3 each)
+                // Keyword matches
                 let matched_keywords: Vec<&String> = entry
                     .keywords
                     .iter()
                     .filter(|kw| tokens.contains(kw))
                     .collect();
                 if !matched_keywords.is_empty() {
-                    score += matched_keywords.len() as f64 * 3.0;
+                    score += matched_keywords.len() as f64 * entry.weights.keyword;
                     let kw_list: Vec<&str> =
                         matched_keywords.iter().map(|s| s.as_str()).collect();
                     explanations.push(format!("matched keywords: [{}]", kw_list.join(", ")));
                 }
 
-                // Verb matches (+2 each)
+                // Fuzzy keyword matches, for typos, credited by similarity
+                let fuzzy_keywords: Vec<(&String, f64)> = entry
+                    .keywords
+                    .iter()
+                    .filter(|kw| !tokens.contains(kw))
+                    .filter_map(|kw| {
+                        tokens
+                            .iter()
+                            .filter_map(|t| fuzzy_similarity(t, kw))
+                            .max_by(f64::total_cmp)
+                            .map(|similarity| (kw, similarity))
+                    })
+                    .collect();
+                if !fuzzy_keywords.is_empty() {
+                    score += fuzzy_keywords
+                        .iter()
+                        .map(|(_, similarity)| similarity * entry.weights.keyword)
+                        .sum::<f64>();
+                    let kw_list: Vec<&str> =
+                        fuzzy_keywords.iter().map(|(kw, _)| kw.as_str()).collect();
+                    explanations.push(format!("fuzzy keywords: [{}]", kw_list.join(", ")));
+                }
+
+                // Verb matches
                 let matched_verbs: Vec<&String> = entry
                     .verbs
                     .iter()
                     .filter(|v| tokens.contains(v))
                     .collect();
                 if !matched_verbs.is_empty() {
-                    score += matched_verbs.len() as f64 * 2.0;
+                    score += matched_verbs.len() as f64 * entry.weights.verb;
                     let v_list: Vec<&str> =
                         matched_verbs.iter().map(|s| s.as_str()).collect();
                     explanations.push(format!("matched verbs: [{}]", v_list.join(", ")));
                 }
 
-                // Object matches (+2 each)
+                // Object matches
                 let matched_objects: Vec<&String> = entry
                     .objects
                     .iter()
                     .filter(|o| tokens.contains(o))
                     .collect();
                 if !matched_objects.is_empty() {
-                    score += matched_objects.len() as f64 * 2.0;
+                    score += matched_objects.len() as f64 * entry.weights.object;
                     let o_list: Vec<&str> =
                         matched_objects.iter().map(|s| s.as_str()).collect();
                     explanations.push(format!("matched objects: [{}]", o_list.join(", ")));
                 }
 
-                // Example substring matches (+4 each)
+                // Example substring matches
                 let matched_examples: Vec<&String> = entry
                     .examples
                     .iter()
                     .filter(|ex| input_lower.contains(ex.as_str()))
                     .collect();
                 if !matched_examples.is_empty() {
-                    score += matched_examples.len() as f64 * 4.0;
+                    score += matched_examples.len() as f64 * entry.weights.example;
                     let ex_list: Vec<&str> =
                         matched_examples.iter().map(|s| s.as_str()).collect();
                     explanations.push(format!("matched examples: [{}]", ex_list.join(", ")));
@@ -227,12 +315,15 @@ impl Default for Router {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::routing::RoutingWeights;
 
     fn make_command(text: &str, tags: Vec<&str>) -> ParsedCommand {
         ParsedCommand {
             raw_text: text.to_string(),
             normalized_text: text.to_string(),
             tags: tags.into_iter().map(|s| s.to_string()).collect(),
+            tokens: vec![],
+            flags: Default::default(),
         }
     }
 
@@ -243,6 +334,7 @@ mod tests {
             examples: vec!["copy this text".into(), "paste from clipboard".into()],
             verbs: vec!["copy".into(), "paste".into(), "cut".into()],
             objects: vec!["clipboard".into(), "text".into(), "selection".into()],
+            weights: Default::default(),
         }
     }
 
@@ -253,6 +345,7 @@ mod tests {
             examples: vec!["write a note".into(), "save this memo".into()],
             verbs: vec!["write".into(), "save".into(), "create".into()],
             objects: vec!["note".into(), "memo".into(), "document".into()],
+            weights: Default::default(),
         }
     }
 
@@ -263,6 +356,7 @@ mod tests {
             examples: vec!["schedule a meeting".into(), "create an event".into()],
             verbs: vec!["schedule".into(), "create".into(), "cancel".into()],
             objects: vec!["meeting".into(), "event".into(), "appointment".into()],
+            weights: Default::default(),
         }
     }
 
@@ -326,6 +420,7 @@ mod tests {
                 examples: vec![],
                 verbs: vec![],
                 objects: vec![],
+                weights: Default::default(),
             });
         }
 
@@ -455,4 +550,151 @@ mod tests {
         assert!(!result.candidates.is_empty());
         assert_eq!(result.candidates[0].app_id, "clipboard");
     }
+
+    #[test]
+    fn quoted_phrase_matches_multi_word_object() {
+        let mut router = Router::new();
+        router.register(RoutingMetadata {
+            app_id: "lists".to_string(),
+            keywords: vec![],
+            examples: vec![],
+            verbs: vec![],
+            objects: vec!["Shopping List".into()],
+            weights: Default::default(),
+        });
+
+        let unquoted = router.route(&crate::command::parse("add milk to shopping list"));
+        assert!(unquoted.candidates.is_empty());
+
+        let quoted = router.route(&crate::command::parse(r#"add milk to "shopping list""#));
+        assert_eq!(quoted.candidates[0].app_id, "lists");
+        assert_eq!(quoted.candidates[0].score, 2.0);
+    }
+
+    #[test]
+    fn re_register_replaces_instead_of_duplicating() {
+        let mut router = Router::new();
+        router.register(clipboard_app());
+        router.register(clipboard_app());
+
+        let cmd = make_command("copy text", vec![]);
+        let first = router.route(&cmd);
+        assert_eq!(first.candidates.len(), 1);
+
+        let mut updated = clipboard_app();
+        updated.verbs = vec!["duplicate".into()];
+        router.register(updated);
+
+        let result = router.route(&cmd);
+        assert_eq!(result.candidates.len(), 1);
+        assert!(result.candidates[0].score < first.candidates[0].score);
+        assert!(!result.candidates[0].explanation.contains("matched verbs"));
+    }
+
+    #[test]
+    fn unregister_removes_app_from_candidates() {
+        let mut router = Router::new();
+        router.register(clipboard_app());
+        router.register(calendar_app());
+
+        assert!(router.unregister("clipboard"));
+        assert!(!router.unregister("clipboard"));
+
+        let result = router.route(&make_command("copy text", vec![]));
+        assert!(result.candidates.iter().all(|c| c.app_id != "clipboard"));
+    }
+
+    #[test]
+    fn boosted_verb_weight_changes_borderline_outcome() {
+        let mut router = Router::new();
+        router.register(RoutingMetadata {
+            app_id: "launcher".to_string(),
+            keywords: vec!["app".into()],
+            examples: vec![],
+            verbs: vec![],
+            objects: vec![],
+            weights: Default::default(),
+        });
+        router.register(RoutingMetadata {
+            app_id: "music".to_string(),
+            keywords: vec![],
+            examples: vec![],
+            verbs: vec!["play".into()],
+            objects: vec![],
+            weights: Default::default(),
+        });
+
+        // Keyword (+3) beats verb (+2) under default weights.
+        let cmd = make_command("play app", vec![]);
+        assert_eq!(router.route(&cmd).candidates[0].app_id, "launcher");
+
+        router.register(RoutingMetadata {
+            app_id: "music".to_string(),
+            keywords: vec![],
+            examples: vec![],
+            verbs: vec!["play".into()],
+            objects: vec![],
+            weights: RoutingWeights {
+                verb: 5.0,
+                ..Default::default()
+            },
+        });
+        let result = router.route(&cmd);
+        assert_eq!(result.candidates[0].app_id, "music");
+        assert_eq!(result.candidates[0].score, 5.0);
+    }
+
+    #[test]
+    fn typo_in_keyword_matches_fuzzily() {
+        let mut router = Router::new();
+        router.register(clipboard_app());
+        router.register(calendar_app());
+
+        let result = router.route(&make_command("open clipbord", vec![]));
+        assert_eq!(result.candidates.len(), 1);
+        assert_eq!(result.candidates[0].app_id, "clipboard");
+        let score = result.candidates[0].score;
+        assert!(score > 1.5 && score < 3.0, "score {score}");
+        assert!(result.candidates[0].explanation.contains("fuzzy keywords: [clipboard]"));
+    }
+
+    #[test]
+    fn swapped_and_wrong_letters_match_fuzzily() {
+        let mut router = Router::new();
+        router.register(clipboard_app());
+        router.register(RoutingMetadata {
+            app_id: "calendar".to_string(),
+            keywords: vec!["calendar".into()],
+            ..calendar_app()
+        });
+
+        let result = router.route(&make_command("clipbaord", vec![]));
+        assert_eq!(result.candidates.len(), 1);
+        assert_eq!(result.candidates[0].app_id, "clipboard");
+
+        let result = router.route(&make_command("calender", vec![]));
+        assert_eq!(result.candidates.len(), 1);
+        assert_eq!(result.candidates[0].app_id, "calendar");
+    }
+
+    #[test]
+    fn closer_typo_earns_more_credit() {
+        let mut router = Router::new();
+        router.register(clipboard_app());
+
+        let missing = router.route(&make_command("clipbord", vec![])).candidates[0].score;
+        let swapped = router.route(&make_command("clipbaord", vec![])).candidates[0].score;
+        assert!(missing > swapped);
+        assert!(missing < 3.0);
+    }
+
+    #[test]
+    fn short_or_distant_tokens_do_not_match_fuzzily() {
+        let mut router = Router::new();
+        router.register(clipboard_app());
+        router.register(calendar_app());
+
+        assert!(router.route(&make_command("clpbrd", vec![])).candidates.is_empty());
+        assert!(router.route(&make_command("cpy", vec![])).candidates.is_empty());
+    }
 }
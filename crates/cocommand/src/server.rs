@@ -1,12 +1,51 @@
 use axum::{routing::get, Router};
+use std::io;
 use std::net::SocketAddr;
+use std::ops::RangeInclusive;
+use std::time::Duration;
 use tokio::net::TcpListener;
 
+/// Host the API server binds to.
+const HOST: &str = "127.0.0.1";
+
+/// Preferred port for the API server.
+pub const DEFAULT_PORT: u16 = 4840;
+
+/// Ports tried, in order, once the default port stays in use after all retries.
+pub const FALLBACK_PORTS: RangeInclusive<u16> = 4841..=4849;
+
 pub async fn start() -> Result<SocketAddr, String> {
-    let app = Router::new().route("/health", get(health));
-    let listener = TcpListener::bind("127.0.0.1:4840")
+    let listener = TcpListener::bind((HOST, DEFAULT_PORT))
         .await
         .map_err(|error| error.to_string())?;
+    serve(listener)
+}
+
+/// Start the server, retrying while the default port is still held by a
+/// lingering instance.
+///
+/// Only "address in use" is retried, with exponential backoff starting at
+/// `initial_delay_ms`. Any other bind error (permission denied, invalid
+/// address) is returned immediately. If the default port is still taken after
+/// `max_attempts`, each port in [`FALLBACK_PORTS`] is tried once; the returned
+/// address reports the port the server settled on.
+pub async fn start_with_retry(
+    max_attempts: u32,
+    initial_delay_ms: u64,
+) -> Result<SocketAddr, String> {
+    let listener = bind_with_retry(
+        DEFAULT_PORT,
+        FALLBACK_PORTS,
+        max_attempts,
+        Duration::from_millis(initial_delay_ms),
+    )
+    .await
+    .map_err(|error| error.to_string())?;
+    serve(listener)
+}
+
+fn serve(listener: TcpListener) -> Result<SocketAddr, String> {
+    let app = Router::new().route("/health", get(health));
     let addr = listener
         .local_addr()
         .map_err(|error| error.to_string())?;
@@ -18,6 +57,94 @@ pub async fn start() -> Result<SocketAddr, String> {
     Ok(addr)
 }
 
+async fn bind_with_retry(
+    port: u16,
+    fallback_ports: RangeInclusive<u16>,
+    max_attempts: u32,
+    initial_delay: Duration,
+) -> io::Result<TcpListener> {
+    let mut delay = initial_delay;
+    let mut last_error = None;
+
+    for attempt in 0..max_attempts.max(1) {
+        if attempt > 0 {
+            tokio::time::sleep(delay).await;
+            delay = delay.saturating_mul(2);
+        }
+        match TcpListener::bind((HOST, port)).await {
+            Ok(listener) => return Ok(listener),
+            Err(error) if is_retryable(&error) => {
+                println!("[server] port {port} in use (attempt {})", attempt + 1);
+                last_error = Some(error);
+            }
+            Err(error) => return Err(error),
+        }
+    }
+
+    for fallback in fallback_ports {
+        match TcpListener::bind((HOST, fallback)).await {
+            Ok(listener) => {
+                println!("[server] port {port} unavailable; using fallback port {fallback}");
+                return Ok(listener);
+            }
+            Err(error) if is_retryable(&error) => last_error = Some(error),
+            Err(error) => return Err(error),
+        }
+    }
+
+    Err(last_error.unwrap_or_else(|| io::Error::from(io::ErrorKind::AddrInUse)))
+}
+
+/// Whether a bind error is worth retrying (another instance still shutting down).
+fn is_retryable(error: &io::Error) -> bool {
+    error.kind() == io::ErrorKind::AddrInUse
+}
+
 async fn health() -> &'static str {
     "ok"
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_addr_in_use_is_retryable() {
+        assert!(is_retryable(&io::Error::from(io::ErrorKind::AddrInUse)));
+        assert!(!is_retryable(&io::Error::from(io::ErrorKind::PermissionDenied)));
+        assert!(!is_retryable(&io::Error::from(io::ErrorKind::AddrNotAvailable)));
+    }
+
+    #[tokio::test]
+    async fn binds_requested_port_when_free() {
+        let probe = std::net::TcpListener::bind((HOST, 0)).unwrap();
+        let port = probe.local_addr().unwrap().port();
+        drop(probe);
+
+        let listener = bind_with_retry(port, 1..=0, 3, Duration::from_millis(1))
+            .await
+            .unwrap();
+        assert_eq!(listener.local_addr().unwrap().port(), port);
+    }
+
+    #[tokio::test]
+    async fn falls_back_when_port_stays_in_use() {
+        let held = std::net::TcpListener::bind((HOST, 0)).unwrap();
+        let port = held.local_addr().unwrap().port();
+
+        // Port 0 as the fallback lets the OS pick any free port.
+        let listener = bind_with_retry(port, 0..=0, 2, Duration::from_millis(1))
+            .await
+            .unwrap();
+        assert_ne!(listener.local_addr().unwrap().port(), port);
+    }
+
+    #[tokio::test]
+    async fn errors_when_all_ports_in_use() {
+        let held = std::net::TcpListener::bind((HOST, 0)).unwrap();
+        let port = held.local_addr().unwrap().port();
+
+        let result = bind_with_retry(port, port..=port, 2, Duration::from_millis(1)).await;
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::AddrInUse);
+    }
+}
@@ -12,6 +12,6 @@ pub use clipboard_store::ClipboardStore;
 pub use event_log::EventLog;
 pub use kv_store::KvStore;
 pub use memory::MemoryStorage;
-pub use snapshot_store::SnapshotStore;
+pub use snapshot_store::{SnapshotStore, DEFAULT_SNAPSHOT_HISTORY};
 pub use traits::Storage;
 pub use types::{event_summary, ClipboardEntry, EventRecord, WorkspaceSnapshot};
@@ -1,5 +1,7 @@
 //! Bounded clipboard history trait and in-memory implementation.
 
+use uuid::Uuid;
+
 use super::types::ClipboardEntry;
 
 const DEFAULT_CLIPBOARD_MAX: usize = 50;
@@ -12,6 +14,10 @@ pub trait ClipboardStore: Send + Sync {
     fn list(&self, limit: usize) -> Vec<ClipboardEntry>;
     /// Get the most recent entry.
     fn latest(&self) -> Option<ClipboardEntry>;
+    /// Get an entry by ID.
+    fn get(&self, id: Uuid) -> Option<ClipboardEntry>;
+    /// Remove all stored entries.
+    fn clear(&mut self);
     /// Number of stored entries.
     fn len(&self) -> usize;
     fn is_empty(&self) -> bool;
@@ -57,6 +63,14 @@ impl ClipboardStore for MemoryClipboardStore {
         self.entries.last().cloned()
     }
 
+    fn get(&self, id: Uuid) -> Option<ClipboardEntry> {
+        self.entries.iter().find(|e| e.id == id).cloned()
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+
     fn len(&self) -> usize {
         self.entries.len()
     }
@@ -70,7 +84,6 @@ impl ClipboardStore for MemoryClipboardStore {
 mod tests {
     use super::*;
     use std::time::SystemTime;
-    use uuid::Uuid;
 
     #[test]
     fn starts_empty() {
@@ -173,4 +186,39 @@ mod tests {
         let latest = clip.latest().unwrap();
         assert_eq!(latest.content, format!("item-{}", DEFAULT_CLIPBOARD_MAX + 9));
     }
+
+    #[test]
+    fn get_by_id() {
+        let mut clip = MemoryClipboardStore::default();
+        let id = Uuid::new_v4();
+        clip.push(ClipboardEntry {
+            id,
+            content: "wanted".to_string(),
+            copied_at: SystemTime::now(),
+        });
+        clip.push(ClipboardEntry {
+            id: Uuid::new_v4(),
+            content: "other".to_string(),
+            copied_at: SystemTime::now(),
+        });
+
+        assert_eq!(clip.get(id).unwrap().content, "wanted");
+        assert!(clip.get(Uuid::new_v4()).is_none());
+    }
+
+    #[test]
+    fn clear_removes_all() {
+        let mut clip = MemoryClipboardStore::default();
+        for i in 0..3 {
+            clip.push(ClipboardEntry {
+                id: Uuid::new_v4(),
+                content: format!("item-{i}"),
+                copied_at: SystemTime::now(),
+            });
+        }
+        clip.clear();
+
+        assert!(clip.is_empty());
+        assert!(clip.latest().is_none());
+    }
 }
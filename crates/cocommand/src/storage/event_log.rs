@@ -1,5 +1,7 @@
 //! Append-only event log trait and in-memory implementation.
 
+use std::sync::mpsc::{self, Receiver, Sender};
+
 use crate::events::Event;
 
 use super::types::{event_summary, EventRecord};
@@ -16,6 +18,9 @@ pub trait EventLog: Send + Sync {
     fn tail(&self, limit: usize) -> Vec<EventRecord>;
     /// Return all records with seq > `seq`.
     fn since(&self, seq: u64) -> Vec<EventRecord>;
+    /// Receive every record appended from now on. Dropping the receiver
+    /// unsubscribes it.
+    fn subscribe(&mut self) -> Receiver<EventRecord>;
 }
 
 // --- Memory Implementation ---
@@ -24,6 +29,7 @@ pub trait EventLog: Send + Sync {
 pub(crate) struct MemoryEventLog {
     records: Vec<EventRecord>,
     next_seq: u64,
+    subscribers: Vec<Sender<EventRecord>>,
 }
 
 impl EventLog for MemoryEventLog {
@@ -36,6 +42,8 @@ impl EventLog for MemoryEventLog {
         };
         self.next_seq += 1;
         self.records.push(record.clone());
+        self.subscribers
+            .retain(|subscriber| subscriber.send(record.clone()).is_ok());
         record
     }
 
@@ -59,6 +67,12 @@ impl EventLog for MemoryEventLog {
             .cloned()
             .collect()
     }
+
+    fn subscribe(&mut self) -> Receiver<EventRecord> {
+        let (sender, receiver) = mpsc::channel();
+        self.subscribers.push(sender);
+        receiver
+    }
 }
 
 #[cfg(test)]
@@ -150,4 +164,25 @@ mod tests {
         assert_eq!(record.seq, 42);
         assert_eq!(record.summary, "Command (5 chars)");
     }
+
+    #[test]
+    fn subscribers_receive_new_appends_only() {
+        let mut log = MemoryEventLog::default();
+        log.append(make_event("before"));
+
+        let receiver = log.subscribe();
+        log.append(make_event("after"));
+
+        let record = receiver.try_recv().unwrap();
+        assert_eq!(record.seq, 1);
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn dropped_subscribers_are_pruned() {
+        let mut log = MemoryEventLog::default();
+        drop(log.subscribe());
+        log.append(make_event("a"));
+        assert!(log.subscribers.is_empty());
+    }
 }
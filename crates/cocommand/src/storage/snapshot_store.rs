@@ -1,20 +1,61 @@
 //! Workspace snapshot store trait and in-memory implementation.
 
+use std::collections::VecDeque;
+
 use super::types::WorkspaceSnapshot;
 
-/// Workspace snapshot persistence (single-slot, overwrite semantics).
+/// Default number of snapshots kept in history.
+pub const DEFAULT_SNAPSHOT_HISTORY: usize = 20;
+
+/// Workspace snapshot persistence.
+///
+/// Holds a single "current" slot (overwrite semantics) plus a bounded
+/// history of recently pushed snapshots for undo and debugging.
 pub trait SnapshotStore: Send + Sync {
     /// Save a workspace snapshot, replacing any previous one.
     fn save(&mut self, snapshot: WorkspaceSnapshot);
     /// Load the stored snapshot, if any.
     fn load(&self) -> Option<WorkspaceSnapshot>;
+    /// Save a snapshot as current and append it to history, evicting the
+    /// oldest entries beyond the history limit. Assigns the snapshot the
+    /// next `sequence` id and returns it.
+    fn push(&mut self, snapshot: WorkspaceSnapshot) -> u64;
+    /// List retained snapshots, oldest first.
+    fn history(&self) -> Vec<WorkspaceSnapshot>;
+    /// Make the history entry with the given `sequence` id current.
+    /// Returns `None` if no such entry is retained.
+    fn restore(&mut self, sequence: u64) -> Option<WorkspaceSnapshot>;
+    /// Set how many snapshots history retains (trimming if needed).
+    fn set_history_limit(&mut self, limit: usize);
 }
 
 // --- Memory Implementation ---
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub(crate) struct MemorySnapshotStore {
     snapshot: Option<WorkspaceSnapshot>,
+    history: VecDeque<WorkspaceSnapshot>,
+    history_limit: usize,
+    next_sequence: u64,
+}
+
+impl Default for MemorySnapshotStore {
+    fn default() -> Self {
+        Self {
+            snapshot: None,
+            history: VecDeque::new(),
+            history_limit: DEFAULT_SNAPSHOT_HISTORY,
+            next_sequence: 1,
+        }
+    }
+}
+
+impl MemorySnapshotStore {
+    fn trim_history(&mut self) {
+        while self.history.len() > self.history_limit {
+            self.history.pop_front();
+        }
+    }
 }
 
 impl SnapshotStore for MemorySnapshotStore {
@@ -25,13 +66,42 @@ impl SnapshotStore for MemorySnapshotStore {
     fn load(&self) -> Option<WorkspaceSnapshot> {
         self.snapshot.clone()
     }
+
+    fn push(&mut self, mut snapshot: WorkspaceSnapshot) -> u64 {
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        snapshot.sequence = sequence;
+        self.history.push_back(snapshot.clone());
+        self.trim_history();
+        self.snapshot = Some(snapshot);
+        sequence
+    }
+
+    fn history(&self) -> Vec<WorkspaceSnapshot> {
+        self.history.iter().cloned().collect()
+    }
+
+    fn restore(&mut self, sequence: u64) -> Option<WorkspaceSnapshot> {
+        let snapshot = self
+            .history
+            .iter()
+            .find(|s| s.sequence == sequence)?
+            .clone();
+        self.snapshot = Some(snapshot.clone());
+        Some(snapshot)
+    }
+
+    fn set_history_limit(&mut self, limit: usize) {
+        self.history_limit = limit;
+        self.trim_history();
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use serde_json::json;
-    use std::time::SystemTime;
+    use std::time::{Duration, SystemTime};
 
     #[test]
     fn starts_empty() {
@@ -44,6 +114,7 @@ mod tests {
         let mut store = MemorySnapshotStore::default();
         store.save(WorkspaceSnapshot {
             session_id: "sess-1".to_string(),
+            sequence: 0,
             captured_at: SystemTime::now(),
             data: json!({"mode": "idle"}),
         });
@@ -57,15 +128,83 @@ mod tests {
         let mut store = MemorySnapshotStore::default();
         store.save(WorkspaceSnapshot {
             session_id: "first".to_string(),
+            sequence: 0,
             captured_at: SystemTime::now(),
             data: json!({}),
         });
         store.save(WorkspaceSnapshot {
             session_id: "second".to_string(),
+            sequence: 0,
             captured_at: SystemTime::now(),
             data: json!({}),
         });
 
         assert_eq!(store.load().unwrap().session_id, "second");
     }
+
+    fn snapshot_at(session_id: &str, secs: u64) -> WorkspaceSnapshot {
+        WorkspaceSnapshot {
+            session_id: session_id.to_string(),
+            sequence: 0,
+            captured_at: SystemTime::UNIX_EPOCH + Duration::from_secs(secs),
+            data: json!({}),
+        }
+    }
+
+    #[test]
+    fn push_keeps_bounded_history() {
+        let mut store = MemorySnapshotStore::default();
+        store.set_history_limit(2);
+        store.push(snapshot_at("a", 1));
+        store.push(snapshot_at("b", 2));
+        store.push(snapshot_at("c", 3));
+
+        let ids: Vec<_> = store.history().into_iter().map(|s| s.session_id).collect();
+        assert_eq!(ids, vec!["b", "c"]);
+        assert_eq!(store.load().unwrap().session_id, "c");
+    }
+
+    #[test]
+    fn save_does_not_touch_history() {
+        let mut store = MemorySnapshotStore::default();
+        store.save(snapshot_at("a", 1));
+        assert!(store.history().is_empty());
+        assert_eq!(store.load().unwrap().session_id, "a");
+    }
+
+    #[test]
+    fn restore_makes_entry_current() {
+        let mut store = MemorySnapshotStore::default();
+        let first = store.push(snapshot_at("a", 1));
+        store.push(snapshot_at("b", 2));
+
+        assert_eq!(store.restore(first).unwrap().session_id, "a");
+        assert_eq!(store.load().unwrap().session_id, "a");
+        assert_eq!(store.history().len(), 2);
+
+        assert!(store.restore(99).is_none());
+        assert_eq!(store.load().unwrap().session_id, "a");
+    }
+
+    #[test]
+    fn snapshots_captured_at_the_same_time_stay_distinct() {
+        let mut store = MemorySnapshotStore::default();
+        let first = store.push(snapshot_at("a", 1));
+        let second = store.push(snapshot_at("b", 1));
+        assert!(second > first);
+
+        assert_eq!(store.restore(first).unwrap().session_id, "a");
+        assert_eq!(store.restore(second).unwrap().session_id, "b");
+    }
+
+    #[test]
+    fn lowering_limit_trims_oldest() {
+        let mut store = MemorySnapshotStore::default();
+        for i in 0..5 {
+            store.push(snapshot_at(&i.to_string(), i));
+        }
+        store.set_history_limit(1);
+        let ids: Vec<_> = store.history().into_iter().map(|s| s.session_id).collect();
+        assert_eq!(ids, vec!["4"]);
+    }
 }
@@ -58,6 +58,11 @@ pub fn event_summary(event: &Event) -> String {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkspaceSnapshot {
     pub session_id: String,
+    /// Identifies the snapshot in history. Assigned by
+    /// [`SnapshotStore::push`](super::SnapshotStore::push); unique and
+    /// increasing within a store, unlike `captured_at`.
+    #[serde(default)]
+    pub sequence: u64,
     pub captured_at: SystemTime,
     pub data: serde_json::Value,
 }
@@ -4,8 +4,10 @@ pub mod invocation;
 pub mod schema;
 pub mod registry;
 pub mod executor;
+pub mod breaker;
 
 pub use invocation::{InvocationStatus, ToolInvocationRecord};
 pub use schema::{ExecutionContext, RiskLevel, ToolDefinition, ToolHandler, validate_schema};
-pub use registry::ToolRegistry;
-pub use executor::{execute_tool, ExecutionResult, ToolExecutionOutcome};
+pub use registry::{ToolInfo, ToolRegistry};
+pub use executor::{execute_tool, DenialKind, ExecutionResult, ToolExecutionOutcome};
+pub use breaker::{BreakerConfig, CircuitBreaker};
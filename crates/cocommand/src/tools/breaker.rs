@@ -0,0 +1,185 @@
+//! Per-tool circuit breaker for intermittently failing tools.
+
+use std::collections::HashMap;
+
+use crate::utils::time;
+
+/// Thresholds controlling when a tool's breaker trips and resets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BreakerConfig {
+    /// Consecutive failures that trip the breaker.
+    pub failure_threshold: u32,
+    /// A failure more than this many seconds after the previous one starts a
+    /// new streak.
+    pub window_secs: u64,
+    /// How long (in seconds) a tripped breaker short-circuits calls.
+    pub cooldown_secs: u64,
+}
+
+impl Default for BreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 3,
+            window_secs: 60,
+            cooldown_secs: 30,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct ToolFailures {
+    consecutive: u32,
+    last_failure_at: u64,
+    open_until: Option<u64>,
+}
+
+/// Tracks consecutive handler failures per tool id.
+///
+/// After `failure_threshold` failures, each within `window_secs` of the one
+/// before, calls to the tool are rejected until `cooldown_secs` have passed.
+/// Permission denials are not failures and should not be recorded.
+#[derive(Debug, Default)]
+pub struct CircuitBreaker {
+    config: BreakerConfig,
+    tools: HashMap<String, ToolFailures>,
+}
+
+impl CircuitBreaker {
+    pub fn new(config: BreakerConfig) -> Self {
+        Self {
+            config,
+            tools: HashMap::new(),
+        }
+    }
+
+    pub fn config(&self) -> &BreakerConfig {
+        &self.config
+    }
+
+    /// Replace the thresholds. Existing failure counts are kept.
+    pub fn set_config(&mut self, config: BreakerConfig) {
+        self.config = config;
+    }
+
+    /// Check whether `tool_id` may run.
+    ///
+    /// Returns `Err(retry_after_secs)` while the breaker is open. Once the
+    /// cooldown has elapsed the breaker resets and the call is allowed.
+    pub fn check(&mut self, tool_id: &str) -> Result<(), u64> {
+        let now = time::now_secs();
+        let Some(failures) = self.tools.get(tool_id) else {
+            return Ok(());
+        };
+        match failures.open_until {
+            Some(open_until) if now < open_until => Err(open_until - now),
+            Some(_) => {
+                self.tools.remove(tool_id);
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Record a successful call, clearing the tool's failure streak.
+    pub fn record_success(&mut self, tool_id: &str) {
+        self.tools.remove(tool_id);
+    }
+
+    /// Record a failed call. Returns `true` if this failure tripped the breaker.
+    pub fn record_failure(&mut self, tool_id: &str) -> bool {
+        let now = time::now_secs();
+        let failures = self.tools.entry(tool_id.to_string()).or_default();
+        if now.saturating_sub(failures.last_failure_at) > self.config.window_secs {
+            failures.consecutive = 0;
+        }
+        failures.last_failure_at = now;
+        failures.consecutive += 1;
+        if failures.open_until.is_none() && failures.consecutive >= self.config.failure_threshold {
+            failures.open_until = Some(now + self.config.cooldown_secs);
+            return true;
+        }
+        false
+    }
+
+    /// Whether the breaker for `tool_id` is currently open.
+    pub fn is_open(&self, tool_id: &str) -> bool {
+        let now = time::now_secs();
+        self.tools
+            .get(tool_id)
+            .and_then(|failures| failures.open_until)
+            .is_some_and(|open_until| now < open_until)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::time::MockClock;
+
+    #[test]
+    fn repeated_failures_trip_breaker() {
+        let _clock = MockClock::at_secs(1_000);
+        let mut breaker = CircuitBreaker::new(BreakerConfig::default());
+
+        assert!(!breaker.record_failure("apps.action"));
+        assert!(!breaker.record_failure("apps.action"));
+        assert!(breaker.check("apps.action").is_ok());
+        assert!(breaker.record_failure("apps.action"));
+
+        assert!(breaker.is_open("apps.action"));
+        assert_eq!(breaker.check("apps.action"), Err(30));
+        assert!(breaker.check("notes.latest").is_ok());
+    }
+
+    #[test]
+    fn breaker_resets_after_cooldown() {
+        let clock = MockClock::at_secs(1_000);
+        let mut breaker = CircuitBreaker::new(BreakerConfig::default());
+        for _ in 0..3 {
+            breaker.record_failure("apps.action");
+        }
+        clock.advance_secs(29);
+        assert_eq!(breaker.check("apps.action"), Err(1));
+
+        clock.advance_secs(1);
+        assert!(breaker.check("apps.action").is_ok());
+        assert!(!breaker.is_open("apps.action"));
+        assert!(!breaker.record_failure("apps.action"));
+    }
+
+    #[test]
+    fn success_clears_failure_streak() {
+        let _clock = MockClock::at_secs(1_000);
+        let mut breaker = CircuitBreaker::new(BreakerConfig::default());
+        breaker.record_failure("apps.action");
+        breaker.record_failure("apps.action");
+        breaker.record_success("apps.action");
+
+        assert!(!breaker.record_failure("apps.action"));
+        assert!(!breaker.is_open("apps.action"));
+    }
+
+    #[test]
+    fn failures_outside_window_start_new_streak() {
+        let clock = MockClock::at_secs(1_000);
+        let mut breaker = CircuitBreaker::new(BreakerConfig::default());
+        breaker.record_failure("apps.action");
+        breaker.record_failure("apps.action");
+        clock.advance_secs(61);
+
+        assert!(!breaker.record_failure("apps.action"));
+        assert!(!breaker.is_open("apps.action"));
+    }
+
+    #[test]
+    fn window_is_measured_from_previous_failure() {
+        let clock = MockClock::at_secs(1_000);
+        let mut breaker = CircuitBreaker::new(BreakerConfig::default());
+        breaker.record_failure("apps.action");
+        clock.advance_secs(40);
+        breaker.record_failure("apps.action");
+        clock.advance_secs(40);
+
+        assert!(breaker.record_failure("apps.action"));
+    }
+}
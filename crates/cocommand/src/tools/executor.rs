@@ -1,11 +1,11 @@
 //! Tool execution pipeline.
 
-use std::time::SystemTime;
 use uuid::Uuid;
 
 use crate::events::event::Event;
-use crate::permissions::{enforce_permissions, EnforcementResult, PermissionStore};
+use crate::permissions::{enforce_permissions_with_limit, EnforcementResult, PermissionStore};
 use crate::storage::{ClipboardStore, EventLog};
+use crate::utils::time;
 use crate::workspace::{ConfirmationPending, Workspace, WorkspaceMode, WorkspacePatch};
 
 use super::invocation::{InvocationStatus, ToolInvocationRecord};
@@ -26,15 +26,91 @@ pub struct ExecutionResult {
 pub enum ToolExecutionOutcome {
     /// The tool was executed successfully.
     Executed(ExecutionResult),
-    /// The tool was denied by the permission system.
+    /// The tool did not produce a result: it was unknown, its input was
+    /// invalid, permissions denied it, or its handler returned an error.
     Denied {
         reason: String,
         invocation: ToolInvocationRecord,
+        /// Which pipeline step stopped the call.
+        kind: DenialKind,
     },
     /// The tool requires user confirmation before execution.
     NeedsConfirmation { confirmation_id: String },
 }
 
+/// Why a [`ToolExecutionOutcome::Denied`] call produced no result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DenialKind {
+    /// The tool is unknown or its input failed schema validation.
+    InvalidCall,
+    /// Permission enforcement denied the call.
+    PermissionDenied,
+    /// The handler ran and returned an error.
+    HandlerFailed,
+}
+
+/// `type` of the structured error returned when permissions deny a tool.
+pub const TOOL_DENIED: &str = "tool_denied";
+
+/// `type` of the structured error returned when a tool's handler fails.
+pub const TOOL_FAILED: &str = "tool_failed";
+
+/// `type` of the structured error returned for an unknown tool or invalid input.
+pub const INVALID_TOOL_CALL: &str = "invalid_tool_call";
+
+/// `type` of the structured error returned when a tool awaits confirmation.
+pub const APPROVAL_REQUIRED: &str = "approval_required";
+
+/// `type` of the structured error returned while a tool's circuit breaker is open.
+pub const TOOL_UNAVAILABLE: &str = "tool_unavailable";
+
+impl ToolExecutionOutcome {
+    /// Whether the tool's handler ran and returned an error, as opposed to
+    /// being blocked by lookup, validation or permissions.
+    pub fn is_handler_failure(&self) -> bool {
+        matches!(
+            self,
+            ToolExecutionOutcome::Denied {
+                kind: DenialKind::HandlerFailed,
+                ..
+            }
+        )
+    }
+
+    /// Convert the outcome into the tool's result or a structured error.
+    ///
+    /// Errors are JSON objects tagged with `type`: [`TOOL_DENIED`],
+    /// [`TOOL_FAILED`] or [`INVALID_TOOL_CALL`] with a `reason`, or
+    /// [`APPROVAL_REQUIRED`] with a `confirmation_id`. Planners and callers
+    /// can tell permission outcomes from failures and malformed calls.
+    pub fn into_result(self, tool_id: &str) -> Result<serde_json::Value, serde_json::Value> {
+        match self {
+            ToolExecutionOutcome::Executed(exec) => Ok(exec.result),
+            ToolExecutionOutcome::NeedsConfirmation { confirmation_id } => Err(serde_json::json!({
+                "type": APPROVAL_REQUIRED,
+                "confirmation_id": confirmation_id,
+                "tool_id": tool_id,
+            })),
+            ToolExecutionOutcome::Denied { reason, kind, .. } => Err(serde_json::json!({
+                "type": kind.error_type(),
+                "tool_id": tool_id,
+                "reason": reason,
+            })),
+        }
+    }
+}
+
+impl DenialKind {
+    /// The structured error `type` reported for this kind of denial.
+    pub fn error_type(self) -> &'static str {
+        match self {
+            DenialKind::InvalidCall => INVALID_TOOL_CALL,
+            DenialKind::PermissionDenied => TOOL_DENIED,
+            DenialKind::HandlerFailed => TOOL_FAILED,
+        }
+    }
+}
+
 /// Execute a tool through the full pipeline: lookup, validate, enforce permissions, execute, emit events.
 pub fn execute_tool(
     registry: &ToolRegistry,
@@ -51,7 +127,7 @@ pub fn execute_tool(
     let tool = match registry.lookup(instance_id, tool_id) {
         Some(t) => t,
         None => {
-            let now = SystemTime::now();
+            let now = time::now();
             let invocation = ToolInvocationRecord::new(
                 tool_id.to_string(),
                 now,
@@ -63,13 +139,14 @@ pub fn execute_tool(
             return ToolExecutionOutcome::Denied {
                 reason: format!("unknown tool: '{tool_id}'"),
                 invocation,
+                kind: DenialKind::InvalidCall,
             };
         }
     };
 
     // 2. Validate input args
     if let Err(e) = validate_schema(&args, &tool.input_schema) {
-        let now = SystemTime::now();
+        let now = time::now();
         let invocation = ToolInvocationRecord::new(
             tool_id.to_string(),
             now,
@@ -81,21 +158,23 @@ pub fn execute_tool(
         return ToolExecutionOutcome::Denied {
             reason: format!("{e}"),
             invocation,
+            kind: DenialKind::InvalidCall,
         };
     }
 
     // 3. Enforce permissions
-    match enforce_permissions(tool, permission_store, tool_call_id) {
+    let max_auto_risk = workspace.config.max_auto_risk.clone();
+    match enforce_permissions_with_limit(tool, permission_store, &max_auto_risk, tool_call_id) {
         EnforcementResult::Allowed => {
             // Emit authorized event
             event_log.append(Event::ToolCallAuthorized {
                 id: Uuid::new_v4(),
-                timestamp: SystemTime::now(),
+                timestamp: time::now(),
                 tool_call_id,
             });
         }
         EnforcementResult::Denied { reason } => {
-            let now = SystemTime::now();
+            let now = time::now();
             let invocation = ToolInvocationRecord::new(
                 tool_id.to_string(),
                 now,
@@ -107,11 +186,15 @@ pub fn execute_tool(
             // Emit denied event
             event_log.append(Event::ToolCallDenied {
                 id: Uuid::new_v4(),
-                timestamp: SystemTime::now(),
+                timestamp: time::now(),
                 tool_call_id,
                 reason: reason.clone(),
             });
-            return ToolExecutionOutcome::Denied { reason, invocation };
+            return ToolExecutionOutcome::Denied {
+                reason,
+                invocation,
+                kind: DenialKind::PermissionDenied,
+            };
         }
         EnforcementResult::NeedsConfirmation { confirmation_id } => {
             // Transition workspace to AwaitingConfirmation
@@ -120,10 +203,7 @@ pub fn execute_tool(
                 confirmation_id: confirmation_id.clone(),
                 tool_id: tool_id.to_string(),
                 args: args.clone(),
-                requested_at: SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap_or_default()
-                    .as_secs(),
+                requested_at: time::now_secs(),
             });
             return ToolExecutionOutcome::NeedsConfirmation { confirmation_id };
         }
@@ -133,14 +213,14 @@ pub fn execute_tool(
     let hash_before = workspace_hash(workspace);
 
     // 5. Execute handler
-    let started_at = SystemTime::now();
+    let started_at = time::now();
     let mut ctx = ExecutionContext {
         workspace,
         event_log,
         clipboard_store,
     };
     let handler_result = (tool.handler)(&args, &mut ctx);
-    let ended_at = SystemTime::now();
+    let ended_at = time::now();
 
     // After handler, re-borrow workspace and event_log from ctx
     let workspace = ctx.workspace;
@@ -166,14 +246,14 @@ pub fn execute_tool(
             // 8. Emit events
             event_log.append(Event::ToolCallExecuted {
                 id: Uuid::new_v4(),
-                timestamp: SystemTime::now(),
+                timestamp: time::now(),
                 tool_call_id,
                 invocation: invocation.clone(),
             });
 
             event_log.append(Event::ToolResultRecorded {
                 id: Uuid::new_v4(),
-                timestamp: SystemTime::now(),
+                timestamp: time::now(),
                 tool_call_id,
                 result: result.clone(),
             });
@@ -182,7 +262,7 @@ pub fn execute_tool(
             if is_kernel && hash_before != hash_after {
                 event_log.append(Event::WorkspacePatched {
                     id: Uuid::new_v4(),
-                    timestamp: SystemTime::now(),
+                    timestamp: time::now(),
                     patch: WorkspacePatch {
                         operations: vec![], // v0: handler mutates directly
                     },
@@ -209,7 +289,7 @@ pub fn execute_tool(
             // 8. Emit ToolCallExecuted with Failed status
             event_log.append(Event::ToolCallExecuted {
                 id: Uuid::new_v4(),
-                timestamp: SystemTime::now(),
+                timestamp: time::now(),
                 tool_call_id,
                 invocation: invocation.clone(),
             });
@@ -217,6 +297,7 @@ pub fn execute_tool(
             ToolExecutionOutcome::Denied {
                 reason: format!("tool '{tool_id}' failed: {e}"),
                 invocation,
+                kind: DenialKind::HandlerFailed,
             }
         }
     }
@@ -671,4 +752,112 @@ mod tests {
         let authorized = records.iter().find(|r| matches!(&r.event, Event::ToolCallAuthorized { .. }));
         assert!(authorized.is_some());
     }
+
+    #[test]
+    fn into_result_tags_permission_outcomes() {
+        let (mut registry, mut workspace, mut storage, mut permission_store) = setup();
+        let mut tool = make_tool("risky", true, make_handler_ok(json!("ok")));
+        tool.risk_level = RiskLevel::Confirm;
+        registry.register_kernel_tool(tool);
+
+        let (event_log, clipboard_store) = storage.split_event_clipboard_mut();
+        let error = execute_tool(
+            &registry, &mut workspace, event_log, clipboard_store, &permission_store,
+            "inst-1", "risky", json!({"name": "x"}), Uuid::new_v4(),
+        )
+        .into_result("risky")
+        .unwrap_err();
+        assert_eq!(error["type"], APPROVAL_REQUIRED);
+        assert!(error["confirmation_id"].is_string());
+
+        workspace.clear_confirmation();
+        permission_store.set_decision(
+            "risky".to_string(),
+            crate::permissions::PermissionScope::Write,
+            crate::permissions::PermissionDecision::Deny,
+        );
+        let (event_log, clipboard_store) = storage.split_event_clipboard_mut();
+        let error = execute_tool(
+            &registry, &mut workspace, event_log, clipboard_store, &permission_store,
+            "inst-1", "risky", json!({"name": "x"}), Uuid::new_v4(),
+        )
+        .into_result("risky")
+        .unwrap_err();
+        assert_eq!(error["type"], TOOL_DENIED);
+        assert!(error["reason"].as_str().unwrap().contains("denied"));
+    }
+
+    #[test]
+    fn handler_failure_distinguished_from_denial() {
+        let (mut registry, mut workspace, mut storage, permission_store) = setup();
+        registry.register_kernel_tool(make_tool("flaky", true, make_handler_fail()));
+
+        let (event_log, clipboard_store) = storage.split_event_clipboard_mut();
+        let failed = execute_tool(
+            &registry, &mut workspace, event_log, clipboard_store, &permission_store,
+            "inst-1", "flaky", json!({"name": "x"}), Uuid::new_v4(),
+        );
+        assert!(failed.is_handler_failure());
+
+        let (event_log, clipboard_store) = storage.split_event_clipboard_mut();
+        let unknown = execute_tool(
+            &registry, &mut workspace, event_log, clipboard_store, &permission_store,
+            "inst-1", "missing", json!({}), Uuid::new_v4(),
+        );
+        assert!(!unknown.is_handler_failure());
+
+        // A blocked call stays a non-failure even if its record carries a code.
+        let coded = ToolExecutionOutcome::Denied {
+            reason: "invalid input".to_string(),
+            invocation: {
+                let now = time::now();
+                let mut invocation = ToolInvocationRecord::new(
+                    "flaky".to_string(),
+                    now,
+                    now,
+                    InvocationStatus::Failed,
+                    String::new(),
+                    String::new(),
+                );
+                invocation.error_code = Some("INVALID_INPUT".to_string());
+                invocation
+            },
+            kind: DenialKind::InvalidCall,
+        };
+        assert!(!coded.is_handler_failure());
+    }
+
+    #[test]
+    fn failing_handler_is_not_reported_as_denied() {
+        let (mut registry, mut workspace, mut storage, permission_store) = setup();
+        registry.register_kernel_tool(make_tool("flaky", true, make_handler_fail()));
+
+        let (event_log, clipboard_store) = storage.split_event_clipboard_mut();
+        let error = execute_tool(
+            &registry, &mut workspace, event_log, clipboard_store, &permission_store,
+            "inst-1", "flaky", json!({"name": "x"}), Uuid::new_v4(),
+        )
+        .into_result("flaky")
+        .unwrap_err();
+        assert_eq!(error["type"], TOOL_FAILED);
+        assert!(error["reason"].as_str().unwrap().contains("handler exploded"));
+
+        let (event_log, clipboard_store) = storage.split_event_clipboard_mut();
+        let error = execute_tool(
+            &registry, &mut workspace, event_log, clipboard_store, &permission_store,
+            "inst-1", "flaky", json!({}), Uuid::new_v4(),
+        )
+        .into_result("flaky")
+        .unwrap_err();
+        assert_eq!(error["type"], INVALID_TOOL_CALL);
+
+        let (event_log, clipboard_store) = storage.split_event_clipboard_mut();
+        let error = execute_tool(
+            &registry, &mut workspace, event_log, clipboard_store, &permission_store,
+            "inst-1", "missing", json!({}), Uuid::new_v4(),
+        )
+        .into_result("missing")
+        .unwrap_err();
+        assert_eq!(error["type"], INVALID_TOOL_CALL);
+    }
 }
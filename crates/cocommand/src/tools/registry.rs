@@ -1,9 +1,37 @@
 //! Tool registry for kernel and instance-scoped tools.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
 
 use crate::workspace::InstanceId;
-use super::schema::ToolDefinition;
+use super::schema::{RiskLevel, ToolDefinition};
+
+/// Session-independent description of a registered tool (for catalogs and docs).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolInfo {
+    pub tool_id: String,
+    /// Owning instance for instance-scoped tools (the extension ID for
+    /// extension tools); `None` for kernel tools.
+    pub instance_id: Option<InstanceId>,
+    pub input_schema: serde_json::Value,
+    pub output_schema: serde_json::Value,
+    pub risk_level: RiskLevel,
+    pub is_kernel: bool,
+}
+
+impl ToolInfo {
+    fn from_definition(instance_id: Option<InstanceId>, def: &ToolDefinition) -> Self {
+        Self {
+            tool_id: def.tool_id.clone(),
+            instance_id,
+            input_schema: def.input_schema.clone(),
+            output_schema: def.output_schema.clone(),
+            risk_level: def.risk_level.clone(),
+            is_kernel: def.is_kernel,
+        }
+    }
+}
 
 /// Registry holding kernel tools (global) and instance-scoped tools.
 ///
@@ -81,6 +109,66 @@ impl ToolRegistry {
             .collect()
     }
 
+    /// Returns tools registered for a single instance as (id, definition) pairs,
+    /// sorted by ID. Tools shadowed by a kernel tool are omitted.
+    pub fn instance_tools(&self, instance_id: &str) -> Vec<(&str, &ToolDefinition)> {
+        let mut tools: Vec<(&str, &ToolDefinition)> = self
+            .instance_tools
+            .iter()
+            .filter(|((iid, tid), _)| iid == instance_id && !self.kernel_tools.contains_key(tid))
+            .map(|((_, tid), def)| (tid.as_str(), def))
+            .collect();
+        tools.sort_by(|a, b| a.0.cmp(b.0));
+        tools
+    }
+
+    /// Returns every registered tool across kernel and all instances.
+    ///
+    /// Kernel tools come first, sorted by ID, followed by instance tools
+    /// sorted by `(instance_id, tool_id)`. Shadowed instance tools are included.
+    /// With `active_instances`, only tools of those instances are listed
+    /// alongside the kernel tools; `None` lists every instance.
+    pub fn catalog(&self, active_instances: Option<&HashSet<InstanceId>>) -> Vec<ToolInfo> {
+        let mut kernel: Vec<ToolInfo> = self
+            .kernel_tools
+            .values()
+            .map(|def| ToolInfo::from_definition(None, def))
+            .collect();
+        kernel.sort_by(|a, b| a.tool_id.cmp(&b.tool_id));
+
+        let mut instance: Vec<ToolInfo> = self
+            .instance_tools
+            .iter()
+            .filter(|((iid, _), _)| active_instances.is_none_or(|active| active.contains(iid)))
+            .map(|((iid, _), def)| ToolInfo::from_definition(Some(iid.clone()), def))
+            .collect();
+        instance.sort_by(|a, b| {
+            a.instance_id
+                .cmp(&b.instance_id)
+                .then_with(|| a.tool_id.cmp(&b.tool_id))
+        });
+
+        kernel.extend(instance);
+        kernel
+    }
+
+    /// Returns sorted tool IDs registered more than once, either by several
+    /// instances or by an instance and the kernel (which shadows it).
+    pub fn colliding_tool_ids(&self) -> Vec<&str> {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for id in self.kernel_tools.keys() {
+            *counts.entry(id.as_str()).or_default() += 1;
+        }
+        for (_, tid) in self.instance_tools.keys() {
+            *counts.entry(tid.as_str()).or_default() += 1;
+        }
+        counts
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(id, _)| id)
+            .collect()
+    }
+
     /// Number of registered kernel tools.
     pub fn kernel_tool_count(&self) -> usize {
         self.kernel_tools.len()
@@ -101,7 +189,7 @@ impl Default for ToolRegistry {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tools::schema::{RiskLevel, ToolHandler};
+    use crate::tools::schema::ToolHandler;
     use serde_json::json;
 
     fn make_handler() -> ToolHandler {
@@ -209,4 +297,80 @@ mod tests {
         reg.register_kernel_tool(make_tool("dup", true));
         reg.register_kernel_tool(make_tool("dup", true));
     }
+
+    #[test]
+    fn instance_tools_scoped_and_unshadowed() {
+        let mut reg = ToolRegistry::new();
+        reg.register_kernel_tool(make_tool("shared", true));
+        reg.register_instance_tool("calendar".to_string(), make_tool("shared", false));
+        reg.register_instance_tool("calendar".to_string(), make_tool("create_event", false));
+        reg.register_instance_tool("calendar".to_string(), make_tool("cancel_event", false));
+        reg.register_instance_tool("notes".to_string(), make_tool("create_note", false));
+
+        let ids: Vec<&str> = reg
+            .instance_tools("calendar")
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(ids, vec!["cancel_event", "create_event"]);
+        assert!(reg.instance_tools("missing").is_empty());
+    }
+
+    #[test]
+    fn catalog_lists_kernel_then_instance_tools() {
+        let mut reg = ToolRegistry::new();
+        reg.register_kernel_tool(make_tool("b_kernel", true));
+        reg.register_kernel_tool(make_tool("a_kernel", true));
+        reg.register_instance_tool("ext-2".to_string(), make_tool("z_tool", false));
+        reg.register_instance_tool("ext-1".to_string(), make_tool("y_tool", false));
+
+        let catalog = reg.catalog(None);
+        let ids: Vec<(&str, Option<&str>)> = catalog
+            .iter()
+            .map(|t| (t.tool_id.as_str(), t.instance_id.as_deref()))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                ("a_kernel", None),
+                ("b_kernel", None),
+                ("y_tool", Some("ext-1")),
+                ("z_tool", Some("ext-2")),
+            ]
+        );
+        assert!(catalog[0].is_kernel);
+        assert_eq!(catalog[2].risk_level, RiskLevel::Safe);
+    }
+
+    #[test]
+    fn catalog_filters_to_active_instances() {
+        let mut reg = ToolRegistry::new();
+        reg.register_kernel_tool(make_tool("kernel", true));
+        reg.register_instance_tool("ext-1".to_string(), make_tool("y_tool", false));
+        reg.register_instance_tool("ext-2".to_string(), make_tool("z_tool", false));
+
+        let active: HashSet<InstanceId> = ["ext-2".to_string()].into();
+        let ids: Vec<String> = reg
+            .catalog(Some(&active))
+            .into_iter()
+            .map(|t| t.tool_id)
+            .collect();
+        assert_eq!(ids, vec!["kernel", "z_tool"]);
+
+        let kernel_only = reg.catalog(Some(&HashSet::new()));
+        assert_eq!(kernel_only.len(), 1);
+        assert!(kernel_only[0].is_kernel);
+    }
+
+    #[test]
+    fn colliding_tool_ids_reports_duplicates() {
+        let mut reg = ToolRegistry::new();
+        reg.register_kernel_tool(make_tool("shared", true));
+        reg.register_instance_tool("ext-1".to_string(), make_tool("shared", false));
+        reg.register_instance_tool("ext-1".to_string(), make_tool("dup", false));
+        reg.register_instance_tool("ext-2".to_string(), make_tool("dup", false));
+        reg.register_instance_tool("ext-2".to_string(), make_tool("unique", false));
+
+        assert_eq!(reg.colliding_tool_ids(), vec!["dup", "shared"]);
+    }
 }
@@ -6,8 +6,8 @@ use crate::error::CoreResult;
 use crate::storage::{ClipboardStore, EventLog};
 use crate::workspace::Workspace;
 
-/// Risk classification for a tool.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// Risk classification for a tool, ordered from least to most risky.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum RiskLevel {
     /// Tool has no side effects.
     Safe,
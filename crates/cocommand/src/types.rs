@@ -1,4 +1,8 @@
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::mpsc::Receiver;
+
+use crate::storage::EventRecord;
 
 /// Request to submit a natural-language command.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -49,6 +53,20 @@ pub enum CoreResponse {
     },
 }
 
+/// Incremental update emitted by `Core::submit_command_stream`.
+///
+/// A stream yields zero or more `Text` deltas followed by exactly one `Done`
+/// carrying the terminal response. Text deltas are provisional; the terminal
+/// response is authoritative (it may be an `Error` even after text arrived).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum CoreResponseDelta {
+    /// A chunk of response text, in production order.
+    Text { chunk: String },
+    /// The terminal response; always the last delta of a stream.
+    Done { response: CoreResponse },
+}
+
 /// A routing candidate (internal use by core orchestration).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RoutedCandidate {
@@ -63,3 +81,43 @@ pub struct ActionSummary {
     pub id: String,
     pub description: String,
 }
+
+impl From<EventRecord> for ActionSummary {
+    fn from(record: EventRecord) -> Self {
+        Self {
+            id: record.event.id().to_string(),
+            description: record.summary,
+        }
+    }
+}
+
+/// Live feed of [`ActionSummary`]s: recent actions first, then each new one
+/// as it is logged.
+#[derive(Debug)]
+pub struct ActionSubscription {
+    backlog: VecDeque<ActionSummary>,
+    records: Receiver<EventRecord>,
+}
+
+impl ActionSubscription {
+    pub(crate) fn new(backlog: Vec<ActionSummary>, records: Receiver<EventRecord>) -> Self {
+        Self {
+            backlog: backlog.into(),
+            records,
+        }
+    }
+
+    /// Block until the next summary. Returns `None` once the event log is gone.
+    pub fn recv(&mut self) -> Option<ActionSummary> {
+        self.backlog
+            .pop_front()
+            .or_else(|| self.records.recv().ok().map(ActionSummary::from))
+    }
+
+    /// Return the next summary if one is ready.
+    pub fn try_recv(&mut self) -> Option<ActionSummary> {
+        self.backlog
+            .pop_front()
+            .or_else(|| self.records.try_recv().ok().map(ActionSummary::from))
+    }
+}
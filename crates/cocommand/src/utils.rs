@@ -0,0 +1,5 @@
+//! Small shared helpers used across core modules.
+
+pub mod fuzzy;
+pub mod sync;
+pub mod time;
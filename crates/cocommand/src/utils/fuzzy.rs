@@ -0,0 +1,207 @@
+//! Scored subsequence matching for routing and command lookup.
+//!
+//! A pattern matches a candidate when all of its characters appear in the
+//! candidate in order, ignoring case. Among all such alignments the
+//! highest-scoring one is returned, favouring consecutive runs and word
+//! starts, so `"gc"` ranks `"git commit"` above `"magic"`.
+
+/// Score for each matched character.
+const MATCH_SCORE: i64 = 16;
+/// Bonus when a matched character directly follows the previous match.
+const CONSECUTIVE_BONUS: i64 = 12;
+/// Bonus when a matched character starts a word.
+const WORD_START_BONUS: i64 = 8;
+/// Penalty per unmatched candidate character between two matches.
+const GAP_PENALTY: i64 = 1;
+
+/// A successful fuzzy match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Higher is better. Only comparable between matches of the same pattern.
+    pub score: i64,
+    /// Char indices in the candidate covered by the match, ascending and
+    /// deduplicated. Usually one per pattern char, but fewer when a char
+    /// that lowercases to several chars (such as `'İ'`) is matched by more
+    /// than one of them.
+    pub positions: Vec<usize>,
+}
+
+/// Match `pattern` against `candidate`, returning the best alignment.
+///
+/// Returns `None` if `pattern` is not a subsequence of `candidate`. An empty
+/// pattern matches everything with score 0.
+pub fn fuzzy_match(pattern: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let pattern: Vec<char> = pattern.chars().flat_map(char::to_lowercase).collect();
+    let original: Vec<char> = candidate.chars().collect();
+    // Lowercase the candidate the same way as the pattern, remembering which
+    // original char each lowered char came from.
+    let (lowered, owner): (Vec<char>, Vec<usize>) = original
+        .iter()
+        .enumerate()
+        .flat_map(|(index, c)| c.to_lowercase().map(move |lower| (lower, index)))
+        .unzip();
+
+    if pattern.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+    if pattern.len() > lowered.len() {
+        return None;
+    }
+
+    let n = lowered.len();
+    // best[i][j]: best score with pattern[i] matched at candidate[j].
+    let mut best: Vec<Vec<Option<i64>>> = vec![vec![None; n]; pattern.len()];
+    let mut parent: Vec<Vec<usize>> = vec![vec![0; n]; pattern.len()];
+
+    for (i, &pc) in pattern.iter().enumerate() {
+        // Running max of best[i-1][k] + GAP_PENALTY * k over k < j - 1, so a
+        // gapped predecessor costs GAP_PENALTY per skipped char.
+        let mut gapped: Option<(i64, usize)> = None;
+        for j in 0..n {
+            if i > 0 && j >= 2 {
+                if let Some(prev) = best[i - 1][j - 2] {
+                    let value = prev + GAP_PENALTY * (j - 2) as i64;
+                    if gapped.is_none_or(|(g, _)| value > g) {
+                        gapped = Some((value, j - 2));
+                    }
+                }
+            }
+            if lowered[j] != pc {
+                continue;
+            }
+            let mut score = MATCH_SCORE;
+            let starts_char = j == 0 || owner[j - 1] != owner[j];
+            if starts_char && is_word_start(&original, owner[j]) {
+                score += WORD_START_BONUS;
+            }
+            if i == 0 {
+                best[i][j] = Some(score);
+                continue;
+            }
+
+            let mut from: Option<(i64, usize)> = None;
+            if j >= 1 {
+                if let Some(prev) = best[i - 1][j - 1] {
+                    from = Some((prev + CONSECUTIVE_BONUS, j - 1));
+                }
+            }
+            if let Some((g, k)) = gapped {
+                let value = g - GAP_PENALTY * (j - 1) as i64;
+                if from.is_none_or(|(f, _)| value > f) {
+                    from = Some((value, k));
+                }
+            }
+            if let Some((prev, k)) = from {
+                best[i][j] = Some(prev + score);
+                parent[i][j] = k;
+            }
+        }
+    }
+
+    let last = pattern.len() - 1;
+    let (end, score) = best[last]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, s)| s.map(|s| (j, s)))
+        // Prefer the earliest end on ties.
+        .fold(None, |acc: Option<(usize, i64)>, (j, s)| match acc {
+            Some((_, best_score)) if best_score >= s => acc,
+            _ => Some((j, s)),
+        })?;
+
+    let mut matched = vec![end; pattern.len()];
+    for i in (1..pattern.len()).rev() {
+        matched[i - 1] = parent[i][matched[i]];
+    }
+    let mut positions: Vec<usize> = matched.into_iter().map(|j| owner[j]).collect();
+    positions.dedup();
+
+    Some(FuzzyMatch { score, positions })
+}
+
+/// Whether the char at `index` begins a word: the first char, a char after a
+/// separator, or an uppercase char after a lowercase one.
+fn is_word_start(chars: &[char], index: usize) -> bool {
+    if index == 0 {
+        return true;
+    }
+    let prev = chars[index - 1];
+    let current = chars[index];
+    !prev.is_alphanumeric() || (prev.is_lowercase() && current.is_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_match_scores_highest() {
+        let exact = fuzzy_match("clipboard", "clipboard").unwrap();
+        let typo = fuzzy_match("clpboard", "clipboard").unwrap();
+        assert_eq!(exact.positions, (0..9).collect::<Vec<_>>());
+        assert!(exact.score > typo.score);
+    }
+
+    #[test]
+    fn typo_with_missing_letter_still_matches() {
+        let m = fuzzy_match("calndar", "calendar").unwrap();
+        assert_eq!(m.positions, vec![0, 1, 2, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn acronym_prefers_word_starts() {
+        let m = fuzzy_match("gc", "git commit").unwrap();
+        assert_eq!(m.positions, vec![0, 4]);
+
+        let acronym = fuzzy_match("gc", "git commit").unwrap();
+        let buried = fuzzy_match("gc", "magic").unwrap();
+        assert!(acronym.score > buried.score);
+    }
+
+    #[test]
+    fn camel_case_boundaries_count_as_word_starts() {
+        let m = fuzzy_match("sn", "showNotes").unwrap();
+        assert_eq!(m.positions, vec![0, 4]);
+    }
+
+    #[test]
+    fn matching_is_case_insensitive() {
+        let m = fuzzy_match("NOTES", "Notes").unwrap();
+        assert_eq!(m.positions, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn multi_char_lowercase_is_handled_on_both_sides() {
+        // 'İ' lowercases to 'i' plus a combining dot.
+        let m = fuzzy_match("İst", "İstanbul").unwrap();
+        assert_eq!(m.positions, vec![0, 1, 2]);
+
+        let m = fuzzy_match("ist", "İstanbul").unwrap();
+        assert_eq!(m.positions, vec![0, 1, 2]);
+        assert!(fuzzy_match("İst", "istanbul").is_none());
+    }
+
+    #[test]
+    fn non_matches_return_none() {
+        assert!(fuzzy_match("xyz", "clipboard").is_none());
+        assert!(fuzzy_match("draobpilc", "clipboard").is_none());
+        assert!(fuzzy_match("clipboards", "clipboard").is_none());
+    }
+
+    #[test]
+    fn empty_pattern_matches_with_zero_score() {
+        let m = fuzzy_match("", "anything").unwrap();
+        assert_eq!(m.score, 0);
+        assert!(m.positions.is_empty());
+    }
+
+    #[test]
+    fn consecutive_run_beats_scattered_match() {
+        let run = fuzzy_match("note", "notes app").unwrap();
+        let scattered = fuzzy_match("note", "new old tab entry").unwrap();
+        assert!(run.score > scattered.score);
+    }
+}
@@ -0,0 +1,49 @@
+//! Mutex helpers shared by `Core` and the LLM tool adapter.
+//!
+//! Locks are recovered rather than propagated when a previous holder
+//! panicked. Recovered lock names are queued and later written to the event
+//! log as `lock_poisoned` errors by [`report_recovered_locks`].
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use uuid::Uuid;
+
+use crate::events::Event;
+use crate::storage::Storage;
+use crate::utils::time;
+
+/// Names of locks recovered from poisoning, not yet logged to storage.
+pub type PoisonedLocks = Mutex<Vec<&'static str>>;
+
+/// Lock `mutex`, recovering the guard if a previous holder panicked.
+///
+/// The protected state stays usable after most panics, so the poison flag is
+/// cleared and `name` is queued in `poisoned` for logging.
+pub fn lock_recovering<'a, T>(
+    mutex: &'a Mutex<T>,
+    name: &'static str,
+    poisoned: &PoisonedLocks,
+) -> MutexGuard<'a, T> {
+    mutex.lock().unwrap_or_else(|error| {
+        mutex.clear_poison();
+        poisoned
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(name);
+        error.into_inner()
+    })
+}
+
+/// Log an `ErrorRaised` event for every lock queued in `poisoned` and clear
+/// the queue.
+pub fn report_recovered_locks(storage: &mut dyn Storage, poisoned: &PoisonedLocks) {
+    let recovered = std::mem::take(&mut *poisoned.lock().unwrap_or_else(PoisonError::into_inner));
+    for name in recovered {
+        storage.event_log_mut().append(Event::ErrorRaised {
+            id: Uuid::new_v4(),
+            timestamp: time::now(),
+            code: "lock_poisoned".to_string(),
+            message: format!("Recovered {name} lock after a panic."),
+        });
+    }
+}
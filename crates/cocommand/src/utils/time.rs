@@ -0,0 +1,125 @@
+//! Clock access for timestamps, TTLs, and tool records.
+//!
+//! Production code reads the system clock through [`now_millis`] and its
+//! helpers. Readings never go backwards within a process, even if the wall
+//! clock jumps. Tests can freeze and advance time on the current thread with
+//! `MockClock` instead of sleeping; it is only compiled for this crate's
+//! tests and with the `test-util` feature.
+
+#[cfg(any(test, feature = "test-util"))]
+use std::cell::Cell;
+#[cfg(any(test, feature = "test-util"))]
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Highest wall-clock reading returned so far, in unix milliseconds.
+static LAST_MILLIS: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(any(test, feature = "test-util"))]
+thread_local! {
+    static MOCK_MILLIS: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Current unix time in milliseconds (mocked time if a `MockClock` is active).
+pub fn now_millis() -> u64 {
+    #[cfg(any(test, feature = "test-util"))]
+    if let Some(mocked) = MOCK_MILLIS.with(Cell::get) {
+        return mocked;
+    }
+    let wall = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+    LAST_MILLIS.fetch_max(wall, Ordering::Relaxed).max(wall)
+}
+
+/// Current unix time in seconds.
+pub fn now_secs() -> u64 {
+    now_millis() / 1000
+}
+
+/// Current time as a [`SystemTime`], at millisecond precision.
+pub fn now() -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(now_millis())
+}
+
+/// Freezes the clock for the current thread until dropped.
+///
+/// Only affects the thread that created it, so parallel tests do not
+/// interfere with each other.
+#[cfg(any(test, feature = "test-util"))]
+pub struct MockClock {
+    // Tied to the creating thread's override.
+    _not_send: PhantomData<*const ()>,
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl MockClock {
+    /// Freeze the clock at `secs` unix seconds.
+    pub fn at_secs(secs: u64) -> Self {
+        MOCK_MILLIS.with(|m| m.set(Some(secs * 1000)));
+        Self {
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Move the frozen clock forward by `secs` seconds.
+    pub fn advance_secs(&self, secs: u64) {
+        self.advance(Duration::from_secs(secs));
+    }
+
+    /// Move the frozen clock forward by `by`.
+    pub fn advance(&self, by: Duration) {
+        MOCK_MILLIS.with(|m| m.set(m.get().map(|ms| ms + by.as_millis() as u64)));
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl Drop for MockClock {
+    fn drop(&mut self) {
+        MOCK_MILLIS.with(|m| m.set(None));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn real_clock_is_non_decreasing() {
+        let first = now_millis();
+        let second = now_millis();
+        assert!(first > 0);
+        assert!(second >= first);
+    }
+
+    #[test]
+    fn mock_clock_freezes_and_advances() {
+        let clock = MockClock::at_secs(1_000);
+        assert_eq!(now_secs(), 1_000);
+        assert_eq!(now_secs(), 1_000);
+
+        clock.advance_secs(90);
+        assert_eq!(now_secs(), 1_090);
+        clock.advance(Duration::from_millis(1_500));
+        assert_eq!(now_millis(), 1_091_500);
+        assert_eq!(now(), UNIX_EPOCH + Duration::from_millis(1_091_500));
+    }
+
+    #[test]
+    fn dropping_mock_restores_real_clock() {
+        {
+            let _clock = MockClock::at_secs(5);
+            assert_eq!(now_secs(), 5);
+        }
+        assert!(now_secs() > 5);
+    }
+
+    #[test]
+    fn mock_is_thread_local() {
+        let _clock = MockClock::at_secs(5);
+        let other = std::thread::spawn(now_secs).join().unwrap();
+        assert!(other > 5);
+    }
+}
@@ -3,13 +3,7 @@ use std::collections::HashMap;
 use crate::error::{CoreError, CoreResult};
 use super::invariants::validate_invariants;
 use super::state::{ApplicationInstance, ApplicationStatus, InstanceId, Workspace};
-
-fn now_timestamp() -> u64 {
-    std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs()
-}
+use crate::utils::time::now_secs;
 
 /// Execute a fallible operation on the workspace with automatic rollback on error.
 fn with_rollback<F, T>(workspace: &mut Workspace, f: F) -> CoreResult<T>
@@ -73,7 +67,7 @@ pub fn open_application(
         };
 
         ws.instances.insert(instance_id.clone(), instance);
-        ws.last_modified = now_timestamp();
+        ws.last_modified = now_secs();
 
         validate_invariants(ws)?;
         Ok(instance_id)
@@ -91,7 +85,7 @@ pub fn close_application(workspace: &mut Workspace, instance_id: &str) -> CoreRe
             if ws.focus.as_deref() == Some(instance_id.as_str()) {
                 ws.focus = None;
             }
-            ws.last_modified = now_timestamp();
+            ws.last_modified = now_secs();
         }
 
         validate_invariants(ws)?;
@@ -115,7 +109,7 @@ pub fn focus_application(workspace: &mut Workspace, instance_id: &str) -> CoreRe
         }
 
         ws.focus = Some(instance_id);
-        ws.last_modified = now_timestamp();
+        ws.last_modified = now_secs();
 
         validate_invariants(ws)?;
         Ok(())
@@ -147,7 +141,7 @@ pub fn mount_tools(
                 instance.mounted_tools.push(tool_id);
             }
         }
-        ws.last_modified = now_timestamp();
+        ws.last_modified = now_secs();
 
         validate_invariants(ws)?;
         Ok(())
@@ -168,7 +162,7 @@ pub fn unmount_tools(
         })?;
 
         instance.mounted_tools.retain(|t| !tool_ids.contains(t));
-        ws.last_modified = now_timestamp();
+        ws.last_modified = now_secs();
 
         validate_invariants(ws)?;
         Ok(())
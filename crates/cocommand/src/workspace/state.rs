@@ -2,6 +2,9 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
 
+use crate::tools::RiskLevel;
+use crate::utils::time;
+
 /// Unique identifier for an application instance.
 pub type InstanceId = String;
 
@@ -68,6 +71,31 @@ pub struct ConfirmationPending {
     pub requested_at: Timestamp,
 }
 
+/// Per-workspace settings, persisted with the workspace snapshot.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorkspaceConfig {
+    /// Highest risk level a tool may run at without confirmation. Tools above
+    /// it always prompt, even when a stored permission allows them.
+    pub max_auto_risk: RiskLevel,
+    /// Workspace-level instructions (persona, constraints) given to the LLM
+    /// planner on every command. Never stored as a visible message.
+    pub system_prompt: Option<String>,
+    /// Cap on LLM steps (tool call, then follow-up call) per command.
+    /// Overrides the planner's own default when set.
+    pub max_tool_iterations: Option<u32>,
+}
+
+impl Default for WorkspaceConfig {
+    fn default() -> Self {
+        Self {
+            max_auto_risk: RiskLevel::Destructive,
+            system_prompt: None,
+            max_tool_iterations: None,
+        }
+    }
+}
+
 /// The workspace state, containing all application instances and session metadata.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Workspace {
@@ -76,6 +104,8 @@ pub struct Workspace {
     pub mode: WorkspaceMode,
     pub follow_up: Option<FollowUpContext>,
     pub confirmation_pending: Option<ConfirmationPending>,
+    #[serde(default)]
+    pub config: WorkspaceConfig,
     pub session_id: String,
     pub created_at: Timestamp,
     pub last_modified: Timestamp,
@@ -84,10 +114,7 @@ pub struct Workspace {
 impl Workspace {
     /// Create a new workspace with the given session ID.
     pub fn new(session_id: String) -> Self {
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
+        let now = time::now_secs();
 
         Self {
             instances: HashMap::new(),
@@ -95,6 +122,7 @@ impl Workspace {
             mode: WorkspaceMode::Idle,
             follow_up: None,
             confirmation_pending: None,
+            config: WorkspaceConfig::default(),
             session_id,
             created_at: now,
             last_modified: now,
@@ -171,10 +199,7 @@ impl Workspace {
 
     /// Get the current unix timestamp in seconds.
     fn now() -> Timestamp {
-        std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs()
+        time::now_secs()
     }
 }
 
@@ -5,10 +5,124 @@ pub struct InstalledApp {
     pub path: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct RunningApp {
+    pub name: String,
+    pub bundle_id: Option<String>,
+    pub pid: i32,
+    pub is_active: bool,
+}
+
+/// A connected display, in global screen points with the origin at the top
+/// left of the main display.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisplayInfo {
+    pub id: u32,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub scale_factor: f64,
+    pub is_main: bool,
+}
+
+impl DisplayInfo {
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppAction {
+    Activate,
+    Hide,
+    Quit,
+}
+
 pub fn list_installed_apps() -> Vec<InstalledApp> {
     Vec::new()
 }
 
+pub fn list_open_apps() -> Vec<RunningApp> {
+    Vec::new()
+}
+
 pub fn open_app_by_bundle_id(_bundle_id: &str) -> Result<(), String> {
     Err("platform-macos not implemented".to_string())
 }
+
+pub fn open_path_with_bundle_id(_path: &str, _bundle_id: &str) -> Result<(), String> {
+    Err("platform-macos not implemented".to_string())
+}
+
+pub fn perform_app_action(_bundle_id: &str, _action: AppAction) -> Result<(), String> {
+    Err("platform-macos not implemented".to_string())
+}
+
+/// The general pasteboard's `changeCount`, which increments on every copy.
+pub fn clipboard_change_count() -> Option<u64> {
+    None
+}
+
+/// Plain-text contents of the general pasteboard, if it holds text.
+pub fn read_clipboard_text() -> Option<String> {
+    None
+}
+
+/// Replace the general pasteboard's contents with `text`.
+pub fn set_clipboard_text(_text: &str) -> Result<(), String> {
+    Err("platform-macos not implemented".to_string())
+}
+
+pub fn list_displays() -> Vec<DisplayInfo> {
+    Vec::new()
+}
+
+/// Current mouse location in global screen points (`NSEvent mouseLocation`,
+/// flipped to a top-left origin).
+pub fn mouse_location() -> Option<(f64, f64)> {
+    None
+}
+
+/// The display containing the mouse cursor, e.g. to open the command palette
+/// on the screen the user is looking at.
+pub fn display_under_cursor() -> Option<DisplayInfo> {
+    let (x, y) = mouse_location()?;
+    display_containing(&list_displays(), x, y)
+}
+
+/// The first display whose bounds contain the point, if any.
+pub fn display_containing(displays: &[DisplayInfo], x: f64, y: f64) -> Option<DisplayInfo> {
+    displays.iter().find(|display| display.contains(x, y)).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn display(id: u32, x: f64, width: f64) -> DisplayInfo {
+        DisplayInfo {
+            id,
+            x,
+            y: 0.0,
+            width,
+            height: 1080.0,
+            scale_factor: 2.0,
+            is_main: id == 1,
+        }
+    }
+
+    #[test]
+    fn point_on_secondary_display_selects_it() {
+        let displays = vec![display(1, 0.0, 1920.0), display(2, 1920.0, 1440.0)];
+        assert_eq!(display_containing(&displays, 2000.0, 500.0).map(|d| d.id), Some(2));
+        assert_eq!(display_containing(&displays, 1919.5, 500.0).map(|d| d.id), Some(1));
+    }
+
+    #[test]
+    fn point_outside_all_displays_is_none() {
+        let displays = vec![display(1, 0.0, 1920.0)];
+        assert_eq!(display_containing(&displays, -10.0, 500.0), None);
+        assert_eq!(display_containing(&displays, 100.0, 1080.0), None);
+    }
+}